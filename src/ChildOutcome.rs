@@ -7,5 +7,7 @@ enum ChildOutcome<Yields: Sized, Complete: Sized>
 {
 	WouldLikeToResume(Yields),
 
+	BudgetExhausted,
+
 	Complete(thread::Result<Complete>),
 }
@@ -43,7 +43,7 @@ pub trait Coroutine: Sized
 
 		let result =
 		{
-			let yielder = Yielder::new(&mut type_safe_transfer);
+			let yielder = Yielder::new(coroutine_instance_handle, &mut type_safe_transfer);
 			catch_unwind(AssertUnwindSafe(|| Self::coroutine(coroutine_instance_handle, yielder, start_child_arguments)))
 		};
 
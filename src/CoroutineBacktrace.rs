@@ -0,0 +1,44 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A symbolized backtrace of a suspended coroutine; see `CoroutineManager::backtrace()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineBacktrace
+{
+	/// Frames, innermost first.
+	pub frames: Vec<CoroutineBacktraceFrame>,
+}
+
+impl Display for CoroutineBacktrace
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		for (index, frame) in self.frames.iter().enumerate()
+		{
+			if frame.symbols.is_empty()
+			{
+				writeln!(f, "{:>4}: {:#018x}", index, frame.instruction_pointer)?;
+			}
+			for symbol in frame.symbols.iter()
+			{
+				writeln!(f, "{:>4}: {:#018x} - {}", index, frame.instruction_pointer, symbol)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl CoroutineBacktrace
+{
+	/// Resolves symbols for return addresses, innermost first, eg those in `WatchdogReport.stack_sample`.
+	#[inline(always)]
+	pub fn resolve(return_addresses: &[usize]) -> Self
+	{
+		Self
+		{
+			frames: return_addresses.iter().map(|&return_address| CoroutineBacktraceFrame::resolve(return_address)).collect(),
+		}
+	}
+}
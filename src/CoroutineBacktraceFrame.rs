@@ -0,0 +1,40 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A frame of a `CoroutineBacktrace`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineBacktraceFrame
+{
+	/// Return address.
+	pub instruction_pointer: usize,
+	
+	/// Symbols, innermost first; there is more than one if functions were inlined, and none if unknown.
+	pub symbols: Vec<CoroutineBacktraceSymbol>,
+}
+
+impl CoroutineBacktraceFrame
+{
+	#[inline(always)]
+	fn resolve(instruction_pointer: usize) -> Self
+	{
+		let mut symbols = Vec::new();
+		
+		// A return address is for the instruction after the call; subtracting 1 finds the call itself, which matters if the call was the last instruction of an inlined function.
+		resolve(instruction_pointer.saturating_sub(1) as *mut c_void, |symbol| symbols.push
+		(
+			CoroutineBacktraceSymbol
+			{
+				name: symbol.name().map(|name| name.to_string()),
+				file: symbol.filename().map(Path::to_path_buf),
+				line: symbol.lineno(),
+			}
+		));
+		
+		Self
+		{
+			instruction_pointer,
+			symbols,
+		}
+	}
+}
@@ -0,0 +1,36 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A symbol resolved for a `CoroutineBacktraceFrame`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineBacktraceSymbol
+{
+	/// Demangled name, if known.
+	pub name: Option<String>,
+	
+	/// Source file, if known.
+	pub file: Option<PathBuf>,
+	
+	/// Source line, if known.
+	pub line: Option<u32>,
+}
+
+impl Display for CoroutineBacktraceSymbol
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{}", self.name.as_deref().unwrap_or("<unknown>"))?;
+		
+		if let Some(ref file) = self.file
+		{
+			write!(f, "\n\t\tat {}", file.display())?;
+			if let Some(line) = self.line
+			{
+				write!(f, ":{}", line)?;
+			}
+		}
+		Ok(())
+	}
+}
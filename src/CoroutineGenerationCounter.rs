@@ -13,4 +13,13 @@ impl CoroutineGenerationCounter
 	{
 		self.0 = self.0.wrapping_add(1)
 	}
+	
+	/// A handle obtained at `was_generation` still refers to a coroutine instance of this generation only if the coroutine instance is live.
+	///
+	/// A coroutine instance that has never been allocated, or has been freed, is not live, whatever its generation.
+	#[inline(always)]
+	fn is_current(self, live: bool, was_generation: Self) -> bool
+	{
+		live && self == was_generation
+	}
 }
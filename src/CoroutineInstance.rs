@@ -6,7 +6,7 @@
 ///
 /// All other fields except for "Initialized once in `initializer()`" must be safe to drop ***REGARDLESS*** of whether they have been initialized.
 #[derive(Debug)]
-struct CoroutineInstance<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized>
+struct CoroutineInstance<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized>
 {
 	// Never initialized.
 	heap: CoroutineHeapMemory<CoroutineHeapSize>,
@@ -15,19 +15,36 @@ struct CoroutineInstance<CoroutineHeapSize: MemorySize, CoroutineStackSize: Memo
 	// Initialized once in `initializer()`.
 	// Updated on `free()`.
 	generation: CoroutineGenerationCounter,
+	/// `false`, as is zeroed memory, if free.
+	live: bool,
 	
 	// Updated on `free()`.
 	// Initialized on allocation in `constructor()`.
 	child_coroutine_is_active: bool,
 	inactive_coroutine_local_allocator: Option<GTACSA::CoroutineLocalAllocator>,
+	join_state: Option<Rc<JoinState<C::Complete, CoroutineHeapSize, GTACSA>>>,
 	
 	// Initialized on allocation in `constructor()`.
+	coroutine_instance_handle: CoroutineInstanceHandle,
+	global_allocator: &'static GTACSA,
+	state: CoroutineInstanceState,
 	inactive_current_allocator_in_use: CurrentAllocatorInUse,
+	inactive_current_coroutine_instance_handle: Option<CoroutineInstanceHandle>,
+	inactive_current_coroutine_information: Option<(NonNull<()>, TypeId)>,
+	inactive_remaining_preemption_budget: RemainingPreemptionBudget,
+	preemption_budget: Option<PreemptionBudget>,
+	coroutine_panic_describer: Option<CoroutinePanicDescriber<CoroutineInformation>>,
+	resumptions_since_budget_exhausted: u64,
+	metrics: CoroutineInstanceMetrics,
+	watchdog_description: Option<WatchdogDescription>,
+	#[cfg(feature = "tracing")] span: Option<Span>,
+	#[cfg(feature = "tracing")] entered_span: Option<EnteredSpan>,
+	#[cfg(feature = "tracing")] suspended_entered_spans: [Option<Id>; 16],
 	type_safe_transfer: TypeSafeTransfer<ChildOutcome<C::Yields, C::Complete>, ParentInstructingChild<C::ResumeArguments>>,
 	coroutine_information: CoroutineInformation,
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> Drop for CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> Drop for CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -36,7 +53,7 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'sta
 	}
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> LargeRingQueueElement for CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> LargeRingQueueElement for CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	const Initialization: LargeRingQueueInitialization<Self> = LargeRingQueueInitialization::CreateFullUsingInitializer(Self::initializer);
 	
@@ -69,7 +86,7 @@ macro_rules! get_field
 	}
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	#[inline(always)]
 	unsafe fn initialize_field<Field: Sized>(field: &mut Field, value_to_initialize_field_with: Field)
@@ -80,15 +97,15 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 	#[inline(always)]
 	unsafe fn initializer(_index: u64, mut non_null_coroutine_instance: NonNull<CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>>)
 	{
-		initialize_field!(non_null_coroutine_instance, generation, CoroutineGenerationCounter::default())
+		initialize_field!(non_null_coroutine_instance, generation, CoroutineGenerationCounter::default());
+		initialize_field!(non_null_coroutine_instance, live, false)
 	}
 	
 	#[inline(always)]
-	fn constructor(mut non_null_coroutine_instance: NonNull<Self>, coroutine_information: CoroutineInformation) -> CoroutineGenerationCounter
+	fn constructor(mut non_null_coroutine_instance: NonNull<Self>, coroutine_instance_handle: CoroutineInstanceHandle, global_allocator: &'static GTACSA, coroutine_information: CoroutineInformation)
 	{
 		let heap: &CoroutineHeapMemory<CoroutineHeapSize> = get_field!(non_null_coroutine_instance, heap);
 		let stack: &CoroutineStackMemory<CoroutineStackSize> = get_field!(non_null_coroutine_instance, stack);
-		let generation: CoroutineGenerationCounter = *get_field!(non_null_coroutine_instance, generation);
 		
 		let coroutine_local_allocator = Some(GTACSA::CoroutineLocalAllocator::new_local_allocator(heap.into_memory_source(), C::LifetimeHint, C::HeapMemoryAllocatorBlockSizeHint));
 		
@@ -104,22 +121,56 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 		
 		unsafe
 		{
+			initialize_field!(non_null_coroutine_instance, live, true);
+			initialize_field!(non_null_coroutine_instance, coroutine_instance_handle, coroutine_instance_handle);
+			initialize_field!(non_null_coroutine_instance, global_allocator, global_allocator);
+			initialize_field!(non_null_coroutine_instance, state, CoroutineInstanceState::NotStarted);
+			initialize_field!(non_null_coroutine_instance, child_coroutine_is_active, false);
 			initialize_field!(non_null_coroutine_instance, inactive_coroutine_local_allocator, coroutine_local_allocator);
+			initialize_field!(non_null_coroutine_instance, join_state, None);
 			initialize_field!(non_null_coroutine_instance, inactive_current_allocator_in_use, CurrentAllocatorInUse::CoroutineLocal);
+			initialize_field!(non_null_coroutine_instance, inactive_current_coroutine_instance_handle, None);
+			initialize_field!(non_null_coroutine_instance, inactive_current_coroutine_information, None);
+			initialize_field!(non_null_coroutine_instance, inactive_remaining_preemption_budget, RemainingPreemptionBudget::default());
+			initialize_field!(non_null_coroutine_instance, preemption_budget, None);
+			initialize_field!(non_null_coroutine_instance, coroutine_panic_describer, None);
+			initialize_field!(non_null_coroutine_instance, resumptions_since_budget_exhausted, 0);
+			initialize_field!(non_null_coroutine_instance, metrics, CoroutineInstanceMetrics::default());
+			initialize_field!(non_null_coroutine_instance, watchdog_description, None);
+			#[cfg(feature = "tracing")] initialize_field!(non_null_coroutine_instance, span, None);
+			#[cfg(feature = "tracing")] initialize_field!(non_null_coroutine_instance, entered_span, None);
+			#[cfg(feature = "tracing")] initialize_field!(non_null_coroutine_instance, suspended_entered_spans, Default::default());
 			initialize_field!(non_null_coroutine_instance, type_safe_transfer, type_safe_transfer);
 			initialize_field!(non_null_coroutine_instance, coroutine_information, coroutine_information);
 		}
-		generation
 	}
 
+	/// Offset of `heap` from the start of a coroutine instance.
+	///
+	/// Does not read `heap`, so `non_null_coroutine_instance` can be for memory that has never been initialized.
 	#[inline(always)]
-	fn start(coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, coroutine_instance_allocator: &mut CoroutineInstanceAllocator<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, global_allocator: &'static GTACSA, start_arguments: C::StartArguments) -> StartOutcome<C::Yields, C::Complete>
+	fn heap_offset(non_null_coroutine_instance: NonNull<Self>) -> usize
+	{
+		let this = non_null_coroutine_instance.as_ptr();
+		let heap = unsafe { addr_of!((*this).heap) };
+		(heap as usize) - (this as usize)
+	}
+
+	#[inline(always)]
+	fn start(coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, coroutine_instance_allocator: &mut CoroutineInstanceAllocator<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, global_allocator: &'static GTACSA, watchdog_describer: Option<WatchdogDescriber<CoroutineInformation>>, coroutine_manager_metrics: &mut CoroutineManagerMetrics, lifecycle_hooks: &mut impl CoroutineLifecycleHooks<C, CoroutineInformation>, start_arguments: C::StartArguments) -> StartOutcome<C::Yields, C::Complete>
 	{
 		let coroutine_instance_handle = coroutine_instance_pointer.as_coroutine_instance_handle();
 		
 		let this = unsafe { coroutine_instance_pointer.as_mut_unchecked(coroutine_instance_allocator) };
-		this.pre_transfer_control_to_coroutine(global_allocator);
+		coroutine_manager_metrics.started();
+		lifecycle_hooks.on_start(coroutine_instance_handle, &this.coroutine_information);
+		flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Start);
+		usdt_probe!(start, coroutine_instance_handle);
+		this.watchdog_description = watchdog_describer.map(|watchdog_describer| watchdog_describer.description(&this.coroutine_information));
+		this.pre_transfer_control_to_coroutine(global_allocator, coroutine_instance_handle);
+		let resumed_at = time_stamp_counter();
 		let child_outcome = this.type_safe_transfer.resume_drop_safe_unsafe_typing((coroutine_instance_handle, start_arguments));
+		this.metrics.resumed(resumed_at, time_stamp_counter(), coroutine_manager_metrics);
 		this.post_transfer_control_to_coroutine(global_allocator);
 		
 		use self::ChildOutcome::*;
@@ -129,31 +180,73 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 			WouldLikeToResume(yields) =>
 			{
 				this.child_coroutine_is_active = true;
+				lifecycle_hooks.on_yield(coroutine_instance_handle, &this.coroutine_information);
+				flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Yield);
+				usdt_probe!(yields, coroutine_instance_handle);
 				
 				StartOutcome::WouldLikeToResume(yields)
 			}
 			
+			BudgetExhausted =>
+			{
+				this.child_coroutine_is_active = true;
+				this.resumptions_since_budget_exhausted = 0;
+				lifecycle_hooks.on_yield(coroutine_instance_handle, &this.coroutine_information);
+				flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Yield);
+				usdt_probe!(yields, coroutine_instance_handle);
+				
+				StartOutcome::BudgetExhausted
+			}
+			
 			Complete(thread_result) =>
 			{
 				this.child_coroutine_is_active = false;
+				coroutine_manager_metrics.finished(thread_result.is_err());
+				let thread_result = thread_result.map_err(|panic_information| CoroutinePanic::wrap(panic_information, global_allocator, coroutine_instance_handle, &this.coroutine_information, this.coroutine_panic_describer));
+				match thread_result
+				{
+					Ok(_) =>
+					{
+						flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Complete);
+						usdt_probe!(complete, coroutine_instance_handle);
+						lifecycle_hooks.on_complete(coroutine_instance_handle, &this.coroutine_information)
+					}
+					
+					Err(ref panic_information) =>
+					{
+						flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Panic);
+						usdt_probe!(panic, coroutine_instance_handle);
+						lifecycle_hooks.on_panic(coroutine_instance_handle, &this.coroutine_information, panic_information.as_ref())
+					}
+				}
 				
+				let thread_result = this.join(thread_result);
 				coroutine_instance_allocator.free_coroutine_instance(coroutine_instance_pointer);
 				match thread_result
 				{
-					Ok(complete) => StartOutcome::Complete(complete),
+					None => StartOutcome::Joined,
+					
+					Some(Ok(complete)) => StartOutcome::Complete(complete),
 					
-					Err(panic_information) => resume_unwind(panic_information),
+					Some(Err(panic_information)) => resume_unwind(panic_information),
 				}
 			}
 		}
 	}
 
 	#[inline(always)]
-	fn resume(coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, coroutine_instance_allocator: &mut CoroutineInstanceAllocator<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, global_allocator: &'static GTACSA, resume_arguments: C::ResumeArguments) -> ResumeOutcome<C::Yields, C::Complete>
+	fn resume(coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, coroutine_instance_allocator: &mut CoroutineInstanceAllocator<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>, global_allocator: &'static GTACSA, coroutine_manager_metrics: &mut CoroutineManagerMetrics, lifecycle_hooks: &mut impl CoroutineLifecycleHooks<C, CoroutineInformation>, resume_arguments: C::ResumeArguments) -> ResumeOutcome<C::Yields, C::Complete>
 	{
+		let coroutine_instance_handle = coroutine_instance_pointer.as_coroutine_instance_handle();
+		
 		let this = unsafe { coroutine_instance_pointer.as_mut_unchecked(coroutine_instance_allocator) };
-		this.pre_transfer_control_to_coroutine(global_allocator);
+		lifecycle_hooks.on_resume(coroutine_instance_handle, &this.coroutine_information);
+		flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Resume);
+		usdt_probe!(resume, coroutine_instance_handle);
+		this.pre_transfer_control_to_coroutine(global_allocator, coroutine_instance_handle);
+		let resumed_at = time_stamp_counter();
 		let child_outcome = this.type_safe_transfer.resume_drop_safe(ParentInstructingChild::Resume(resume_arguments));
+		this.metrics.resumed(resumed_at, time_stamp_counter(), coroutine_manager_metrics);
 		this.post_transfer_control_to_coroutine(global_allocator);
 
 		use self::ChildOutcome::*;
@@ -163,20 +256,55 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 			WouldLikeToResume(yields) =>
 			{
 				this.child_coroutine_is_active = true;
+				lifecycle_hooks.on_yield(coroutine_instance_handle, &this.coroutine_information);
+				flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Yield);
+				usdt_probe!(yields, coroutine_instance_handle);
 				
 				ResumeOutcome::WouldLikeToResume(yields)
 			},
+			
+			BudgetExhausted =>
+			{
+				this.child_coroutine_is_active = true;
+				this.resumptions_since_budget_exhausted = 0;
+				lifecycle_hooks.on_yield(coroutine_instance_handle, &this.coroutine_information);
+				flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Yield);
+				usdt_probe!(yields, coroutine_instance_handle);
+				
+				ResumeOutcome::BudgetExhausted
+			},
 
 			Complete(thread_result) =>
 			{
 				this.child_coroutine_is_active = false;
+				coroutine_manager_metrics.finished(thread_result.is_err());
+				let thread_result = thread_result.map_err(|panic_information| CoroutinePanic::wrap(panic_information, global_allocator, coroutine_instance_handle, &this.coroutine_information, this.coroutine_panic_describer));
+				match thread_result
+				{
+					Ok(_) =>
+					{
+						flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Complete);
+						usdt_probe!(complete, coroutine_instance_handle);
+						lifecycle_hooks.on_complete(coroutine_instance_handle, &this.coroutine_information)
+					}
+					
+					Err(ref panic_information) =>
+					{
+						flight_recorder_record(coroutine_instance_handle, FlightRecorderEventKind::Panic);
+						usdt_probe!(panic, coroutine_instance_handle);
+						lifecycle_hooks.on_panic(coroutine_instance_handle, &this.coroutine_information, panic_information.as_ref())
+					}
+				}
 				
+				let thread_result = this.join(thread_result);
 				coroutine_instance_allocator.free_coroutine_instance(coroutine_instance_pointer);
 				match thread_result
 				{
-					Ok(complete) => ResumeOutcome::Complete(complete),
+					None => ResumeOutcome::Joined,
+					
+					Some(Ok(complete)) => ResumeOutcome::Complete(complete),
 
-					Err(panic_information) => resume_unwind(panic_information),
+					Some(Err(panic_information)) => resume_unwind(panic_information),
 				}
 			}
 		}
@@ -188,29 +316,106 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 		let this = unsafe { coroutine_instance.as_mut() };
 		
 		this.generation.increment();
+		this.live = false;
+		
+		if let Some(join_state) = this.join_state.take()
+		{
+			join_state.cancelled()
+		}
 		
 		if this.child_coroutine_is_active
 		{
+			this.child_coroutine_is_active = false;
+			
+			// A killed coroutine must not be told its budget is exhausted whilst it unwinds.
+			this.preemption_budget = None;
+			
+			let global_allocator = this.global_allocator;
+			
 			use self::ChildOutcome::*;
 			
-			match this.type_safe_transfer.resume_drop_safe(ParentInstructingChild::Kill)
+			loop
 			{
-				WouldLikeToResume(_) => panic!("A killed coroutine MUST NOT return `WouldLikeToResume`"),
-				
-				Complete(Err(panic_information)) => resume_unwind(panic_information),
+				this.pre_transfer_control_to_coroutine(global_allocator, this.coroutine_instance_handle);
+				let child_outcome = this.type_safe_transfer.resume_drop_safe(ParentInstructingChild::Kill);
+				this.post_transfer_control_to_coroutine(global_allocator);
 				
-				Complete(Ok(_)) => (),
+				match child_outcome
+				{
+					WouldLikeToResume(_) => panic!("A killed coroutine MUST NOT return `WouldLikeToResume`"),
+					
+					// Budget checks are no-ops once killed (see `Yielder`); should one still yield, it is told again that it has been killed.
+					BudgetExhausted => continue,
+					
+					Complete(Err(panic_information)) => resume_unwind(CoroutinePanic::wrap(panic_information, global_allocator, this.coroutine_instance_handle, &this.coroutine_information, this.coroutine_panic_describer)),
+					
+					Complete(Ok(_)) => break,
+				}
 			}
 		}
 		
+		#[cfg(feature = "tracing")]
+		{
+			this.span = None;
+			this.suspended_entered_spans = Default::default();
+		}
+		
 		// Force `drop()` of the allocator (if required).
 		// LocalAllocators shouldn't rely on `impl Drop` though.
 		this.inactive_coroutine_local_allocator = None;
 	}
 	
+	/// Passes how the coroutine finished to its `JoinHandle`, if it has one which has not been dropped; otherwise returns it.
+	///
+	/// Must be called before the coroutine instance is freed.
+	#[inline(always)]
+	fn join(&mut self, thread_result: thread::Result<C::Complete>) -> Option<thread::Result<C::Complete>>
+	{
+		match self.join_state.take()
+		{
+			None => Some(thread_result),
+			
+			Some(join_state) => if Rc::strong_count(&join_state) == 1
+			{
+				Some(thread_result)
+			}
+			else
+			{
+				join_state.finished(thread_result);
+				None
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn join_handle(&mut self, run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>) -> Option<JoinHandle<C::Complete, CoroutineHeapSize, GTACSA>>
+	{
+		if self.join_state.is_some()
+		{
+			return None
+		}
+		
+		let join_state = JoinState::new(run_queue);
+		self.join_state = Some(join_state.clone());
+		Some(JoinHandle(join_state))
+	}
+	
 	#[inline(always)]
-	fn pre_transfer_control_to_coroutine(&mut self, global_allocator: &'static GTACSA)
+	fn pre_transfer_control_to_coroutine(&mut self, global_allocator: &'static GTACSA, coroutine_instance_handle: CoroutineInstanceHandle)
 	{
+		// Entered before switching allocator as a subscriber may allocate.
+		#[cfg(feature = "tracing")]
+		{
+			self.entered_span = self.span.take().map(Span::entered);
+			self.reenter_suspended_entered_spans();
+		}
+		
+		self.state = CoroutineInstanceState::Running;
+		self.resumptions_since_budget_exhausted = self.resumptions_since_budget_exhausted.saturating_add(1);
+		watchdog_switched_to(Some(coroutine_instance_handle), self.stack_bottom(), self.stack_top(), self.watchdog_description.as_ref());
+		self.inactive_remaining_preemption_budget = replace_remaining_preemption_budget(RemainingPreemptionBudget::replenished(self.preemption_budget, self.resumptions_since_budget_exhausted));
+		self.inactive_current_coroutine_instance_handle = replace_current_coroutine_instance_handle(Some(coroutine_instance_handle));
+		self.inactive_current_coroutine_information = replace_current_coroutine_information(Some((NonNull::from(&mut self.coroutine_information).cast(), TypeId::of::<CoroutineInformation>())));
 		self.inactive_coroutine_local_allocator = global_allocator.swap_coroutine_local_allocator(self.read_inactive_coroutine_local_allocator());
 		self.inactive_current_allocator_in_use = global_allocator.replace_current_allocator_in_use(self.inactive_current_allocator_in_use);
 	}
@@ -220,6 +425,127 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: Glob
 	{
 		self.inactive_current_allocator_in_use = global_allocator.replace_current_allocator_in_use(self.inactive_current_allocator_in_use);
 		self.inactive_coroutine_local_allocator = global_allocator.swap_coroutine_local_allocator(self.read_inactive_coroutine_local_allocator());
+		replace_current_coroutine_information(self.inactive_current_coroutine_information);
+		replace_current_coroutine_instance_handle(self.inactive_current_coroutine_instance_handle);
+		replace_remaining_preemption_budget(self.inactive_remaining_preemption_budget);
+		watchdog_switched_to(self.inactive_current_coroutine_instance_handle, 0, 0, None);
+		self.state = CoroutineInstanceState::Suspended;
+		
+		#[cfg(feature = "tracing")]
+		{
+			self.exit_entered_spans_to_suspend();
+			self.span = self.entered_span.take().map(EnteredSpan::exit);
+		}
+	}
+	
+	/// Exits spans entered by the coroutine that are still entered (eg held across a yield), innermost first, so that they do not remain entered whilst the coroutine is suspended.
+	///
+	/// At most 16 spans are exited; none are exited if the coroutine's own span is disabled, as then spans entered by the coroutine can not be told apart from those of its parent.
+	#[cfg(feature = "tracing")]
+	#[inline(always)]
+	fn exit_entered_spans_to_suspend(&mut self)
+	{
+		let coroutine_span_id = match self.entered_span.as_ref().and_then(|entered_span| entered_span.id())
+		{
+			None => return,
+			
+			Some(coroutine_span_id) => coroutine_span_id,
+		};
+		
+		let suspended_entered_spans = &mut self.suspended_entered_spans;
+		dispatcher::get_default(|dispatch|
+		{
+			for suspended_entered_span in suspended_entered_spans.iter_mut()
+			{
+				let current_span_id = match dispatch.current_span().id()
+				{
+					None => return,
+					
+					Some(current_span_id) => current_span_id.clone(),
+				};
+				
+				if current_span_id == coroutine_span_id
+				{
+					return
+				}
+				
+				dispatch.exit(&current_span_id);
+				if dispatch.current_span().id() == Some(&current_span_id)
+				{
+					return
+				}
+				*suspended_entered_span = Some(current_span_id);
+			}
+		})
+	}
+	
+	/// Re-enters the spans exited by `exit_entered_spans_to_suspend()`, outermost first.
+	#[cfg(feature = "tracing")]
+	#[inline(always)]
+	fn reenter_suspended_entered_spans(&mut self)
+	{
+		let suspended_entered_spans = &mut self.suspended_entered_spans;
+		dispatcher::get_default(|dispatch|
+		{
+			for suspended_entered_span in suspended_entered_spans.iter_mut().rev()
+			{
+				if let Some(span_id) = suspended_entered_span.take()
+				{
+					dispatch.enter(&span_id)
+				}
+			}
+		})
+	}
+	
+	/// Return addresses of a suspended coroutine, innermost first, found by walking the chain of frame pointers (`rbp`) saved when it was suspended.
+	///
+	/// Returns `None` if not suspended.
+	/// Only frames within the coroutine's stack are walked, so the result is truncated for code compiled without frame pointers.
+	#[inline(always)]
+	fn suspended_stack(&self) -> Option<Vec<usize>>
+	{
+		const MaximumDepth: usize = 128;
+		const FrameSize: usize = 2 * size_of::<usize>();
+		
+		if self.state != CoroutineInstanceState::Suspended
+		{
+			return None
+		}
+		
+		let SuspendedRegisters { stack_pointer, mut frame_pointer, instruction_pointer } = self.type_safe_transfer.suspended_registers();
+		let stack_top = self.stack_top();
+		
+		let mut suspended_stack = Vec::with_capacity(MaximumDepth);
+		suspended_stack.push(instruction_pointer);
+		while suspended_stack.len() < MaximumDepth && frame_pointer >= stack_pointer && frame_pointer + FrameSize <= stack_top && frame_pointer % size_of::<usize>() == 0
+		{
+			let frame = frame_pointer as *const usize;
+			let return_address = unsafe { *frame.add(1) };
+			let next_frame_pointer = unsafe { *frame };
+			
+			suspended_stack.push(return_address);
+			
+			if next_frame_pointer <= frame_pointer
+			{
+				break
+			}
+			frame_pointer = next_frame_pointer;
+		}
+		Some(suspended_stack)
+	}
+	
+	/// Lowest address of the stack.
+	#[inline(always)]
+	fn stack_bottom(&self) -> usize
+	{
+		&self.stack as *const CoroutineStackMemory<CoroutineStackSize> as usize
+	}
+	
+	/// Highest address of the stack; on x86-64, stacks grow downwards.
+	#[inline(always)]
+	fn stack_top(&self) -> usize
+	{
+		(&self.stack as *const CoroutineStackMemory<CoroutineStackSize> as usize) + size_of::<CoroutineStackMemory<CoroutineStackSize>>()
 	}
 	
 	/// Borrow checker hack to avoid the need to use `self.inactive_coroutine_local_allocator.take()`, which also writes-back to memory.
@@ -3,9 +3,18 @@
 
 
 /// Allocator of coroutine instances.
-struct CoroutineInstanceAllocator<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized>(LargeRingQueue<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>);
+struct CoroutineInstanceAllocator<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized>
+{
+	large_ring_queue: LargeRingQueue<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>,
+	
+	/// The mapped memory is rounded up to a (huge) page size, so can hold more coroutine instances than the ring queue has slots for.
+	capacity: usize,
+	
+	/// One more than the highest index of a coroutine instance ever obtained; no coroutine instance at or above it has been live.
+	high_water_mark: usize,
+}
 
-impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> Debug for CoroutineInstanceAllocator<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> Debug for CoroutineInstanceAllocator<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -14,28 +23,64 @@ impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + Glo
 	}
 }
 
-impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> CoroutineInstanceAllocator<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> CoroutineInstanceAllocator<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>
 {
 	#[inline(always)]
 	fn new(ideal_maximum_number_of_coroutines: NonZeroU64, defaults: &DefaultHugePageSizes) -> Result<Self, LargeRingQueueCreationError>
 	{
-		LargeRingQueue::new(ideal_maximum_number_of_coroutines, defaults, 0, false).map(Self)
+		let large_ring_queue = LargeRingQueue::new(ideal_maximum_number_of_coroutines, defaults, 0, false)?;
+		let capacity = min(ideal_maximum_number_of_coroutines.get() as usize, large_ring_queue.mapped_size_in_bytes() / size_of::<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>());
+		Ok
+		(
+			Self
+			{
+				large_ring_queue,
+				capacity,
+				high_water_mark: 0,
+			}
+		)
 	}
 	
 	#[inline(always)]
-	fn new_coroutine_instance(&mut self, our_coroutine_manager_index: CoroutineManagerIndex, coroutine_information: CoroutineInformation) -> Result<CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>, AllocError>
+	fn new_coroutine_instance(&mut self, our_coroutine_manager_index: CoroutineManagerIndex, global_allocator: &'static GTACSA, coroutine_information: CoroutineInformation) -> Result<CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>, AllocError>
 	{
-		let base_pointer: NonNull<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>> = self.0.virtual_address().into();
+		let base_pointer: NonNull<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>> = self.large_ring_queue.virtual_address().into();
 		
-		self.0.obtain_and_map
+		let coroutine_instance_pointer = self.large_ring_queue.obtain_and_map
 		(
 			|coroutine_instance|
 			{
-				let generation = CoroutineInstance::constructor(coroutine_instance, coroutine_information);
-				CoroutineInstancePointer(TaggedRelativePointerToData::new(true, our_coroutine_manager_index, UserBits::Zero, generation, coroutine_instance, base_pointer))
+				let generation = *get_field!(coroutine_instance, generation);
+				let coroutine_instance_pointer = CoroutineInstancePointer(TaggedRelativePointerToData::new(true, our_coroutine_manager_index, UserBits::Zero, generation, coroutine_instance, base_pointer));
+				CoroutineInstance::constructor(coroutine_instance, coroutine_instance_pointer.as_coroutine_instance_handle(), global_allocator, coroutine_information);
+				coroutine_instance_pointer
 			},
 			|| AllocError
-		)
+		)?;
+		
+		self.high_water_mark = max(self.high_water_mark, coroutine_instance_pointer.as_coroutine_instance_handle().index() + 1);
+		Ok(coroutine_instance_pointer)
+	}
+	
+	/// Coroutine instances that are live, ie have been obtained by `new_coroutine_instance()` and not yet freed.
+	///
+	/// Only coroutine instances below the high water mark are visited.
+	#[inline(always)]
+	fn live_coroutine_instances(&self, our_coroutine_manager_index: CoroutineManagerIndex) -> impl Iterator<Item=(CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>, NonNull<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>)>
+	{
+		let base_pointer: NonNull<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>> = self.large_ring_queue.virtual_address().into();
+		
+		(0 .. self.high_water_mark).filter_map(move |index|
+		{
+			let coroutine_instance = unsafe { NonNull::new_unchecked(base_pointer.as_ptr().add(index)) };
+			if !*get_field!(coroutine_instance, live)
+			{
+				return None
+			}
+			
+			let generation = *get_field!(coroutine_instance, generation);
+			Some((CoroutineInstancePointer(TaggedRelativePointerToData::new(true, our_coroutine_manager_index, UserBits::Zero, generation, coroutine_instance, base_pointer)), coroutine_instance))
+		})
 	}
 	
 	#[inline(always)]
@@ -43,14 +88,34 @@ impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + Glo
 	{
 		if let Some(non_null_coroutine_instance) = coroutine_instance_pointer.pointer(self)
 		{
+			if *get_field!(non_null_coroutine_instance, child_coroutine_is_active)
+			{
+				flight_recorder_record(coroutine_instance_pointer.as_coroutine_instance_handle(), FlightRecorderEventKind::Kill)
+			}
 			CoroutineInstance::free(non_null_coroutine_instance);
-			self.0.relinquish(non_null_coroutine_instance)
+			self.large_ring_queue.relinquish(non_null_coroutine_instance)
 		}
 	}
 	
+	#[inline(always)]
+	fn io_uring_fixed_buffers(&self, registration: IoUringFixedBuffersRegistration) -> Result<IoUringFixedBuffers, IoUringFixedBuffersError>
+	{
+		let mapped_memory = self.mapped_memory();
+		let base_pointer: NonNull<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>> = mapped_memory.virtual_address().into();
+		
+		IoUringFixedBuffers::new(registration, base_pointer.as_ptr() as usize, self.capacity(), size_of::<CoroutineInstance<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>(), CoroutineInstance::heap_offset(base_pointer), size_of::<CoroutineHeapMemory<CoroutineHeapSize>>())
+	}
+	
+	/// Number of coroutine instances, ie slots in the ring queue.
+	#[inline(always)]
+	fn capacity(&self) -> usize
+	{
+		self.capacity
+	}
+	
 	#[inline(always)]
 	fn mapped_memory(&self) -> &MappedMemory
 	{
-		&self.0
+		&self.large_ring_queue
 	}
 }
@@ -19,7 +19,7 @@ impl CoroutineInstanceHandle
 	
 	const CoroutineManagerIndexBitCount: u64 = 8;
 	const CoroutineManagerIndexBitShift: u64 = Self::UserBitsBitCount + Self::UserBitsBitShift;
-	const CoroutineManagerIndexBitMask: u64 = Self::bit_mask(Self::ReservedBitCount, Self::ReservedBitShift);
+	const CoroutineManagerIndexBitMask: u64 = Self::bit_mask(Self::CoroutineManagerIndexBitCount, Self::CoroutineManagerIndexBitShift);
 	
 	const UserBitsBitCount: u64 = 4;
 	const UserBitsBitShift: u64 = Self::GenerationBitCount + Self::GenerationBitShift;
@@ -58,7 +58,7 @@ impl CoroutineInstanceHandle
 		
 		Self
 		(
-			is_coroutine_unshifted << Self::IsCoroutineBitCount
+			is_coroutine_unshifted << Self::IsCoroutineBitShift
 			| coroutine_manager_index << Self::CoroutineManagerIndexBitShift
 			| user_bits_unshifted << Self::UserBitsBitShift
 			| generation_unshifted << Self::GenerationBitShift
@@ -80,6 +80,46 @@ impl CoroutineInstanceHandle
 		self.0 & Self::IsCoroutineBitMask != 0
 	}
 	
+	/// The `CoroutineInformation` of the coroutine instance this handle is for, if it is the coroutine currently running on this thread and `CoroutineInformation` is the type used by its `CoroutineManager`.
+	///
+	/// Use this from inside a coroutine to reach its own information; `Yielder::coroutine_information()` does not need `unsafe`.
+	///
+	/// # Safety
+	///
+	/// The reference must not be held across a yield.
+	#[inline(always)]
+	pub unsafe fn coroutine_information<'a, CoroutineInformation: 'static + Sized>(self) -> Option<&'a CoroutineInformation>
+	{
+		self.current_coroutine_information::<CoroutineInformation>().map(|coroutine_information| coroutine_information.as_ref())
+	}
+	
+	/// The `CoroutineInformation` of the coroutine instance this handle is for, if it is the coroutine currently running on this thread and `CoroutineInformation` is the type used by its `CoroutineManager`.
+	///
+	/// # Safety
+	///
+	/// See `coroutine_information()`.
+	#[inline(always)]
+	pub unsafe fn coroutine_information_mut<'a, CoroutineInformation: 'static + Sized>(self) -> Option<&'a mut CoroutineInformation>
+	{
+		self.current_coroutine_information::<CoroutineInformation>().map(|mut coroutine_information| coroutine_information.as_mut())
+	}
+	
+	#[inline(always)]
+	fn current_coroutine_information<CoroutineInformation: 'static + Sized>(self) -> Option<NonNull<CoroutineInformation>>
+	{
+		if current_coroutine_instance_handle() != Some(self)
+		{
+			return None
+		}
+		
+		match current_coroutine_information()
+		{
+			Some((coroutine_information, type_identifier)) if type_identifier == TypeId::of::<CoroutineInformation>() => Some(coroutine_information.cast()),
+			
+			_ => None,
+		}
+	}
+	
 	/// Coroutine manager index.
 	#[inline(always)]
 	pub const fn coroutine_manager_index(self) -> CoroutineManagerIndex
@@ -110,6 +150,13 @@ impl CoroutineInstanceHandle
 		CoroutineGenerationCounter(((self.0 & Self::GenerationBitMask) >> Self::GenerationBitShift) as u32)
 	}
 	
+	/// Index of the coroutine instance in the memory of its coroutine manager.
+	#[inline(always)]
+	const fn index(self) -> usize
+	{
+		((self.0 & Self::IndexBitMask) >> Self::IndexBitShift) as usize
+	}
+	
 	#[inline(always)]
 	fn into_absolute_pointer<T: Sized>(self, base_pointer: NonNull<T>) -> NonNull<T>
 	{
@@ -122,8 +169,13 @@ impl CoroutineInstanceHandle
 	#[inline(always)]
 	fn relative_pointer<T: Sized>(self) -> usize
 	{
-		let index = (self.0 & Self::IndexBitMask) >> Self::IndexBitShift;
-		(index as usize) * size_of::<T>()
+		self.relative_pointer_for_size(size_of::<T>())
+	}
+	
+	#[inline(always)]
+	fn relative_pointer_for_size(self, size: usize) -> usize
+	{
+		self.index() * size
 	}
 	
 	#[inline(always)]
@@ -0,0 +1,41 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Metrics of a live coroutine instance.
+///
+/// Cycles are measured using the time stamp counter (`rdtsc`).
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineInstanceMetrics
+{
+	/// Times control has been transferred to this coroutine, including when started.
+	pub resumes: u64,
+	
+	/// Total cycles spent running this coroutine.
+	pub on_cpu_cycles: u64,
+	
+	/// Value of the time stamp counter when control was last transferred to this coroutine.
+	pub last_resumed_at: u64,
+}
+
+impl CoroutineInstanceMetrics
+{
+	/// Cycles elapsed since control was last transferred to this coroutine.
+	#[inline(always)]
+	pub fn cycles_since_last_resume(&self) -> u64
+	{
+		time_stamp_counter().saturating_sub(self.last_resumed_at)
+	}
+	
+	#[inline(always)]
+	fn resumed(&mut self, resumed_at: u64, suspended_at: u64, coroutine_manager_metrics: &mut CoroutineManagerMetrics)
+	{
+		let on_cpu_cycles = suspended_at.saturating_sub(resumed_at);
+		
+		self.resumes += 1;
+		self.on_cpu_cycles += on_cpu_cycles;
+		self.last_resumed_at = resumed_at;
+		
+		coroutine_manager_metrics.resumed(on_cpu_cycles)
+	}
+}
@@ -3,9 +3,9 @@
 
 
 /// A pointer to a CoroutineInstance.
-pub struct CoroutineInstancePointer<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized>(TaggedRelativePointerToData<CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>>);
+pub struct CoroutineInstancePointer<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized>(TaggedRelativePointerToData<CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>>);
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> Debug for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> Debug for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
 	{
@@ -13,7 +13,7 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'sta
 	}
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> Clone for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> Clone for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	#[inline(always)]
 	fn clone(&self) -> Self
@@ -22,11 +22,11 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'sta
 	}
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> Copy for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> Copy for CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 }
 
-impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized> CoroutineInstancePointer<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>
 {
 	/// From a `CoroutineInstanceHandle`.
 	#[inline(always)]
@@ -35,19 +35,22 @@ impl<CoroutineHeapSize: MemorySize, CoroutineStackSize: MemorySize, GTACSA: 'sta
 		Self(TaggedRelativePointerToData::from_handle(coroutine_instance_handle))
 	}
 	
-	/// Only returns `Some()` if the generation matches.
+	/// Only returns `Some()` if the coroutine instance is live and the generation matches.
 	///
 	/// Generations are used to manage memory that is recycled but to which something still maintains a `CoroutineInstancePointer`.
 	///
 	/// This can happen if using `CoroutineInstancePointer` with epoll or io_uring as user data (a user token).
+	///
+	/// The generation alone is not enough: a crafted handle can name a coroutine instance that has never been allocated or has been freed but whose generation has not been reused.
 	#[inline(always)]
 	fn pointer(self, allocator: &CoroutineInstanceAllocator<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>) -> Option<NonNull<CoroutineInstance<CoroutineHeapSize, CoroutineStackSize, GTACSA, C, CoroutineInformation>>>
 	{
 		let absolute_pointer = self.into_absolute_pointer(allocator);
 		
-		let current_generation = (unsafe { absolute_pointer.as_ref() }).generation;
+		let live = *get_field!(absolute_pointer, live);
+		let current_generation = *get_field!(absolute_pointer, generation);
 		
-		if current_generation == self.was_generation()
+		if current_generation.is_current(live, self.was_generation())
 		{
 			Some(absolute_pointer)
 		}
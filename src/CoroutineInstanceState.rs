@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// State of a live coroutine instance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CoroutineInstanceState
+{
+	/// Allocated but control has not yet been transferred to it.
+	NotStarted,
+	
+	/// Control is currently transferred to it (it, or a coroutine it has resumed, is executing).
+	Running,
+	
+	/// Yielded (or exhausted its preemption budget) and is awaiting resumption.
+	Suspended,
+}
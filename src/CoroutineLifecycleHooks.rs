@@ -0,0 +1,53 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Callbacks made by a `CoroutineManager` as its coroutines start, resume, yield and finish, eg for metrics, audit logging or tenant accounting.
+///
+/// All callbacks do nothing by default; use `NoCoroutineLifecycleHooks` if none are needed, which compiles away.
+///
+/// Callbacks are made outside of the coroutine, using the allocator in use when the coroutine was started or resumed.
+pub trait CoroutineLifecycleHooks<C: Coroutine, CoroutineInformation: Sized>
+{
+	/// Called before a coroutine is started.
+	#[inline(always)]
+	fn on_start(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation)
+	{
+		let _ = (coroutine_instance_handle, coroutine_information);
+	}
+	
+	/// Called before a coroutine is resumed.
+	#[inline(always)]
+	fn on_resume(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation)
+	{
+		let _ = (coroutine_instance_handle, coroutine_information);
+	}
+	
+	/// Called after a coroutine has yielded (including because its `PreemptionBudget` was exhausted).
+	#[inline(always)]
+	fn on_yield(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation)
+	{
+		let _ = (coroutine_instance_handle, coroutine_information);
+	}
+	
+	/// Called after a coroutine has completed, before it is freed.
+	#[inline(always)]
+	fn on_complete(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation)
+	{
+		let _ = (coroutine_instance_handle, coroutine_information);
+	}
+	
+	/// Called after a coroutine has panicked, before it is freed and the panic resumed (or passed to its `JoinHandle`).
+	#[inline(always)]
+	fn on_panic(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation, panic_information: &(dyn Any + Send))
+	{
+		let _ = (coroutine_instance_handle, coroutine_information, panic_information);
+	}
+	
+	/// Called before a coroutine is cancelled (killed) using `CoroutineManager::cancel_coroutine()`, including on behalf of scopes and timers.
+	#[inline(always)]
+	fn on_cancel(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation)
+	{
+		let _ = (coroutine_instance_handle, coroutine_information);
+	}
+}
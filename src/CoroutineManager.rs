@@ -3,33 +3,118 @@
 
 
 /// Manages a particular type of coroutine.
+///
+/// `LifecycleHooks` are called as coroutines start, resume, yield and finish.
 #[derive(Debug)]
-pub struct CoroutineManager<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized>
+pub struct CoroutineManager<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation> = NoCoroutineLifecycleHooks>
 {
 	global_allocator: &'static GTACSA,
+	lifecycle_hooks: LifecycleHooks,
+	preemption_budget: Option<PreemptionBudget>,
+	watchdog_describer: Option<WatchdogDescriber<CoroutineInformation>>,
+	coroutine_panic_describer: Option<CoroutinePanicDescriber<CoroutineInformation>>,
+	metrics: CoroutineManagerMetrics,
+	#[cfg(feature = "tracing")] span_factory: CoroutineSpanFactory<CoroutineInformation>,
+	scope_requests: Option<Rc<ScopeRequests<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>>,
 	coroutine_instance_allocator: CoroutineInstanceAllocator<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>,
 	index: CoroutineManagerIndex,
 }
 
-impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: Sized> CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>
+impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation> + Default> CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>
 {
 	/// New instance.
 	///
 	/// `index` is a zero-based value used when looking up coroutines when they are encoded in tokens or user data for use with epoll or io_uring.
 	#[inline(always)]
 	pub fn new(index: CoroutineManagerIndex, global_allocator: &'static GTACSA, ideal_maximum_number_of_coroutines: NonZeroU64, defaults: &DefaultHugePageSizes) -> Result<Self, LargeRingQueueCreationError>
+	{
+		Self::new_with_lifecycle_hooks(index, global_allocator, ideal_maximum_number_of_coroutines, defaults, LifecycleHooks::default())
+	}
+}
+
+impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>> CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>
+{
+	/// New instance with `lifecycle_hooks`.
+	///
+	/// See `new()`.
+	#[inline(always)]
+	pub fn new_with_lifecycle_hooks(index: CoroutineManagerIndex, global_allocator: &'static GTACSA, ideal_maximum_number_of_coroutines: NonZeroU64, defaults: &DefaultHugePageSizes, lifecycle_hooks: LifecycleHooks) -> Result<Self, LargeRingQueueCreationError>
 	{
 		Ok
 		(
 			Self
 			{
 				global_allocator,
+				lifecycle_hooks,
+				preemption_budget: None,
+				watchdog_describer: None,
+				coroutine_panic_describer: None,
+				metrics: CoroutineManagerMetrics::default(),
+				#[cfg(feature = "tracing")] span_factory: CoroutineSpanFactory::default(),
+				scope_requests: None,
 				coroutine_instance_allocator: CoroutineInstanceAllocator::new(ideal_maximum_number_of_coroutines, defaults)?,
 				index,
 			}
 		)
 	}
 	
+	/// Lifecycle hooks.
+	#[inline(always)]
+	pub fn lifecycle_hooks(&self) -> &LifecycleHooks
+	{
+		&self.lifecycle_hooks
+	}
+	
+	/// Lifecycle hooks.
+	#[inline(always)]
+	pub fn lifecycle_hooks_mut(&mut self) -> &mut LifecycleHooks
+	{
+		&mut self.lifecycle_hooks
+	}
+	
+	/// Sets the `PreemptionBudget` given to coroutines when they are started (unless started with `start_coroutine_with_preemption_budget()`); `None`, the default, is unlimited.
+	///
+	/// It is replenished each time they are started or resumed, and does not change for coroutines already started.
+	#[inline(always)]
+	pub fn set_preemption_budget(&mut self, preemption_budget: Option<PreemptionBudget>)
+	{
+		self.preemption_budget = preemption_budget
+	}
+	
+	/// Sets how a `Watchdog` describes the `CoroutineInformation` of coroutines it reports; `None`, the default, does not describe them.
+	#[inline(always)]
+	pub fn set_watchdog_describer(&mut self, watchdog_describer: Option<WatchdogDescriber<CoroutineInformation>>)
+	{
+		self.watchdog_describer = watchdog_describer
+	}
+	
+	/// Sets how `CoroutinePanic.coroutine_information` is described for coroutines started afterwards; `None`, the default, does not describe them.
+	#[inline(always)]
+	pub fn set_coroutine_panic_describer(&mut self, coroutine_panic_describer: Option<CoroutinePanicDescriber<CoroutineInformation>>)
+	{
+		self.coroutine_panic_describer = coroutine_panic_describer
+	}
+	
+	/// Sets how the `tracing` span of each coroutine instance is created when it is started.
+	///
+	/// The span is entered whenever control is transferred to the coroutine and exited whenever control is transferred back.
+	/// Spans entered by the coroutine and still entered when it yields (at most 16) are exited whilst it is suspended and re-entered when it is resumed.
+	#[cfg(feature = "tracing")]
+	#[inline(always)]
+	pub fn set_span_factory(&mut self, span_factory: CoroutineSpanFactory<CoroutineInformation>)
+	{
+		self.span_factory = span_factory
+	}
+	
+	/// Sets the requests made by scopes of coroutines of this coroutine manager; `None`, the default, means coroutines can not use scopes.
+	///
+	/// Once set, children of a scope which is cancelled or dropped are cancelled before control returns from this coroutine manager.
+	#[inline(always)]
+	pub fn set_scope_requests(&mut self, scope_requests: Option<Rc<ScopeRequests<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>>)
+	{
+		self.scope_requests = scope_requests
+	}
+	
 	/// Starts the coroutine; execution will transfer to the coroutine.
 	///
 	/// Execution does not start (returns `Err(AllocError)`) if there is not memory available to start the coroutine.
@@ -42,8 +127,24 @@ impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + Glo
 	#[inline(always)]
 	pub fn start_coroutine(&mut self, coroutine_information: CoroutineInformation, start_arguments: C::StartArguments) -> Result<StartOutcome<C::Yields, C::Complete>, AllocError>
 	{
-		let coroutine_instance_pointer = self.coroutine_instance_allocator.new_coroutine_instance(self.index, coroutine_information)?;
-		Ok(CoroutineInstance::start(coroutine_instance_pointer, &mut self.coroutine_instance_allocator, self.global_allocator, start_arguments))
+		self.start_coroutine_with_preemption_budget(coroutine_information, self.preemption_budget, start_arguments)
+	}
+	
+	/// As `start_coroutine()`, but the coroutine is given `preemption_budget` rather than the one set with `set_preemption_budget()`.
+	#[inline(always)]
+	pub fn start_coroutine_with_preemption_budget(&mut self, coroutine_information: CoroutineInformation, preemption_budget: Option<PreemptionBudget>, start_arguments: C::StartArguments) -> Result<StartOutcome<C::Yields, C::Complete>, AllocError>
+	{
+		let coroutine_instance_pointer = self.new_coroutine_instance(coroutine_information, preemption_budget)?;
+		Ok(self.cancelling_scope_children_afterwards(|this| CoroutineInstance::start(coroutine_instance_pointer, &mut this.coroutine_instance_allocator, this.global_allocator, this.watchdog_describer, &mut this.metrics, &mut this.lifecycle_hooks, start_arguments)))
+	}
+	
+	/// As `start_coroutine()`, but also returns the handle of the coroutine instance.
+	#[inline(always)]
+	fn start_coroutine_returning_handle(&mut self, coroutine_information: CoroutineInformation, start_arguments: C::StartArguments) -> Result<(CoroutineInstanceHandle, StartOutcome<C::Yields, C::Complete>), AllocError>
+	{
+		let coroutine_instance_pointer = self.new_coroutine_instance(coroutine_information, self.preemption_budget)?;
+		let coroutine_instance_handle = coroutine_instance_pointer.as_coroutine_instance_handle();
+		Ok((coroutine_instance_handle, self.cancelling_scope_children_afterwards(|this| CoroutineInstance::start(coroutine_instance_pointer, &mut this.coroutine_instance_allocator, this.global_allocator, this.watchdog_describer, &mut this.metrics, &mut this.lifecycle_hooks, start_arguments))))
 	}
 	
 	/// Ownership of `resume_arguments` will also transfer.
@@ -54,16 +155,325 @@ impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + Glo
 	#[inline(always)]
 	pub fn resume_coroutine(&mut self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>, resume_arguments: C::ResumeArguments) -> ResumeOutcome<C::Yields, C::Complete>
 	{
-		CoroutineInstance::resume(coroutine_instance_pointer, &mut self.coroutine_instance_allocator, self.global_allocator, resume_arguments)
+		self.cancelling_scope_children_afterwards(|this| CoroutineInstance::resume(coroutine_instance_pointer, &mut this.coroutine_instance_allocator, this.global_allocator, &mut this.metrics, &mut this.lifecycle_hooks, resume_arguments))
 	}
 	
 	/// Cancels (kills) an active, but not running, coroutine awaiting its resumption and frees memory.
 	#[inline(always)]
 	pub fn cancel_coroutine(&mut self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>)
 	{
+		self.cancelling_scope_children_afterwards(|this| this.cancel_coroutine_only(coroutine_instance_pointer))
+	}
+	
+	/// Cancels the children of scopes cancelled or dropped whilst control was transferred to a coroutine in `transfer`, even if `transfer` panics.
+	///
+	/// Cancelling a child can drop its own scopes, so this continues until there are no more cancellations.
+	#[inline(always)]
+	fn cancelling_scope_children_afterwards<R>(&mut self, transfer: impl FnOnce(&mut Self) -> R) -> R
+	{
+		let result = catch_unwind(AssertUnwindSafe(|| transfer(self)));
+		
+		if let Some(scope_requests) = self.scope_requests.clone()
+		{
+			while let Some(coroutine_instance_handle) = scope_requests.pop_cancel()
+			{
+				if let Some(coroutine_instance_pointer) = self.live_coroutine_instance_pointer(coroutine_instance_handle)
+				{
+					self.cancel_coroutine_only(coroutine_instance_pointer)
+				}
+			}
+		}
+		
+		match result
+		{
+			Ok(result) => result,
+			
+			Err(panic_information) => resume_unwind(panic_information),
+		}
+	}
+	
+	#[inline(always)]
+	fn cancel_coroutine_only(&mut self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>)
+	{
+		if let Some(non_null_coroutine_instance) = coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator)
+		{
+			self.metrics.cancelled();
+			usdt_probe!(cancel, coroutine_instance_pointer.as_coroutine_instance_handle());
+			self.lifecycle_hooks.on_cancel(coroutine_instance_pointer.as_coroutine_instance_handle(), get_field!(non_null_coroutine_instance, coroutine_information))
+		}
 		self.coroutine_instance_allocator.free_coroutine_instance(coroutine_instance_pointer)
 	}
 	
+	/// Returns a `CoroutineInstancePointer` for `coroutine_instance_handle` if the coroutine instance is still live, ie it has not completed nor been cancelled since the handle was obtained.
+	///
+	/// Use this to discard stale handles, eg those in user data (tokens) from epoll or io_uring or from timers.
+	///
+	/// Also returns `None` for a handle that is not for a coroutine, is for another coroutine manager, is out of range or names a coroutine instance that has never been allocated or has been freed; any `u64` can safely be passed.
+	#[inline(always)]
+	pub fn live_coroutine_instance_pointer(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> Option<CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>
+	{
+		if unlikely!(!coroutine_instance_handle.is_coroutine())
+		{
+			return None
+		}
+		
+		if unlikely!(coroutine_instance_handle.coroutine_manager_index() != self.index)
+		{
+			return None
+		}
+		
+		if unlikely!(coroutine_instance_handle.index() >= self.coroutine_instance_allocator.capacity())
+		{
+			return None
+		}
+		
+		let coroutine_instance_pointer = unsafe { CoroutineInstancePointer::from_handle(coroutine_instance_handle) };
+		coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator).map(|_| coroutine_instance_pointer)
+	}
+	
+	/// Coroutine information of a live coroutine instance.
+	///
+	/// Returns `None` if the coroutine instance has completed or been cancelled.
+	#[inline(always)]
+	pub fn coroutine_information(&self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>) -> Option<&CoroutineInformation>
+	{
+		coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator).map(|non_null_coroutine_instance| get_field!(non_null_coroutine_instance, coroutine_information))
+	}
+	
+	/// Coroutine information of a live coroutine instance.
+	///
+	/// Returns `None` if the coroutine instance has completed or been cancelled.
+	#[inline(always)]
+	pub fn coroutine_information_mut(&mut self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>) -> Option<&mut CoroutineInformation>
+	{
+		coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator).map(|mut non_null_coroutine_instance| &mut unsafe { non_null_coroutine_instance.as_mut() }.coroutine_information)
+	}
+	
+	/// Metrics of a live coroutine instance.
+	///
+	/// Returns `None` if the coroutine instance has completed or been cancelled.
+	#[inline(always)]
+	pub fn coroutine_instance_metrics(&self, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>) -> Option<CoroutineInstanceMetrics>
+	{
+		coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator).map(|non_null_coroutine_instance| *get_field!(non_null_coroutine_instance, metrics))
+	}
+	
+	/// A snapshot of the metrics of this coroutine manager.
+	///
+	/// Use `CoroutineManagerMetricsSnapshot::write_prometheus_text()` to export it.
+	#[inline(always)]
+	pub fn metrics_snapshot(&self) -> CoroutineManagerMetricsSnapshot
+	{
+		CoroutineManagerMetricsSnapshot
+		{
+			coroutine_manager_index: self.index,
+			capacity: self.coroutine_instance_allocator.capacity() as u64,
+			metrics: self.metrics,
+		}
+	}
+	
+	/// Iterates over live coroutine instances, ie those started and not yet completed or cancelled.
+	///
+	/// Use this for graceful shutdown, to list coroutines or to find leaks.
+	#[inline(always)]
+	pub fn iter_live(&self) -> impl Iterator<Item=(CoroutineInstanceHandle, &CoroutineInformation, CoroutineInstanceState)>
+	{
+		self.coroutine_instance_allocator.live_coroutine_instances(self.index).map(|(coroutine_instance_pointer, non_null_coroutine_instance)|
+		{
+			let coroutine_instance = unsafe { non_null_coroutine_instance.as_ref() };
+			(coroutine_instance_pointer.as_coroutine_instance_handle(), &coroutine_instance.coroutine_information, coroutine_instance.state)
+		})
+	}
+	
+	/// As `iter_live()`, but with mutable access to `CoroutineInformation`.
+	#[inline(always)]
+	pub fn iter_live_mut(&mut self) -> impl Iterator<Item=(CoroutineInstanceHandle, &mut CoroutineInformation, CoroutineInstanceState)>
+	{
+		self.coroutine_instance_allocator.live_coroutine_instances(self.index).map(|(coroutine_instance_pointer, mut non_null_coroutine_instance)|
+		{
+			let coroutine_instance = unsafe { non_null_coroutine_instance.as_mut() };
+			(coroutine_instance_pointer.as_coroutine_instance_handle(), &mut coroutine_instance.coroutine_information, coroutine_instance.state)
+		})
+	}
+	
+	/// A symbolized backtrace of where a live coroutine instance is suspended.
+	///
+	/// Returns `None` if the coroutine instance has completed or been cancelled, or is not suspended.
+	/// Frames are found by walking frame pointers, so code should be compiled with `-C force-frame-pointers=yes`.
+	#[inline(always)]
+	pub fn backtrace(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> Option<CoroutineBacktrace>
+	{
+		let non_null_coroutine_instance = self.live_coroutine_instance_pointer(coroutine_instance_handle)?.pointer(&self.coroutine_instance_allocator)?;
+		let suspended_stack = unsafe { non_null_coroutine_instance.as_ref() }.suspended_stack()?;
+		Some(CoroutineBacktrace::resolve(&suspended_stack))
+	}
+	
+	/// Records the stack of every suspended coroutine instance in `parked_stack_profile`.
+	///
+	/// Use this to find out where large numbers of coroutines are blocked.
+	#[inline(always)]
+	pub fn profile_parked_stacks(&self, parked_stack_profile: &mut ParkedStackProfile)
+	{
+		for (_, non_null_coroutine_instance) in self.coroutine_instance_allocator.live_coroutine_instances(self.index)
+		{
+			if let Some(suspended_stack) = unsafe { non_null_coroutine_instance.as_ref() }.suspended_stack()
+			{
+				parked_stack_profile.record(suspended_stack)
+			}
+		}
+	}
+	
+	/// Creates a `JoinHandle` so that another coroutine can wait for this coroutine to finish.
+	///
+	/// Returns `None` if the coroutine instance has completed or been cancelled, or if it already has a `JoinHandle`.
+	///
+	/// # Safety
+	///
+	/// The `C::Complete` value of the coroutine must not own memory allocated from its coroutine-local heap (eg it must be allocated using `RunQueue::outside_coroutine_local_heap()`), as it outlives that heap.
+	#[inline(always)]
+	pub unsafe fn join_handle(&mut self, run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, coroutine_instance_pointer: CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>) -> Option<JoinHandle<C::Complete, CoroutineHeapSize, GTACSA>>
+	{
+		let mut non_null_coroutine_instance = coroutine_instance_pointer.pointer(&self.coroutine_instance_allocator)?;
+		non_null_coroutine_instance.as_mut().join_handle(run_queue)
+	}
+	
+	/// Starts coroutines as requested by scopes of the `ScopeRequests` set with `set_scope_requests()`.
+	///
+	/// Call this whenever a coroutine yields and the scope requests are not empty.
+	/// `started` is called with the outcome of starting each child.
+	#[inline(always)]
+	pub fn process_scope_requests(&mut self, mut started: impl FnMut(&mut Self, CoroutineInstanceHandle, StartOutcome<C::Yields, C::Complete>))
+	{
+		let scope_requests = match self.scope_requests.clone()
+		{
+			None => return,
+			
+			Some(scope_requests) => scope_requests,
+		};
+		
+		while let Some(scope_request) = scope_requests.pop()
+		{
+			use self::ScopeRequest::*;
+			
+			match scope_request
+			{
+				Spawn { scope_state, coroutine_information, start_arguments } =>
+				{
+					{
+						let mut scope_state = scope_state.borrow_mut();
+						scope_state.pending_spawns -= 1;
+						scope_state.waiting.wake_all(&scope_requests.run_queue);
+						if scope_state.cancelled || scope_state.first_failure.is_some()
+						{
+							continue
+						}
+					}
+					
+					let coroutine_instance_pointer = match self.new_coroutine_instance(coroutine_information, self.preemption_budget)
+					{
+						Ok(coroutine_instance_pointer) => coroutine_instance_pointer,
+						
+						Err(AllocError) =>
+						{
+							let mut scope_state = scope_state.borrow_mut();
+							if scope_state.failed(Err(AllocError))
+							{
+								for &(coroutine_instance_handle, _) in scope_state.children.iter()
+								{
+									scope_requests.push(Cancel(coroutine_instance_handle))
+								}
+							}
+							continue
+						}
+					};
+					
+					let coroutine_instance_handle = coroutine_instance_pointer.as_coroutine_instance_handle();
+					let join_handle = unsafe { coroutine_instance_pointer.as_mut_unchecked(&self.coroutine_instance_allocator) }.join_handle(&scope_requests.run_queue).expect("A new coroutine instance does not have a JoinHandle");
+					scope_state.borrow_mut().children.push((coroutine_instance_handle, join_handle));
+					drop(scope_state);
+					
+					let start_outcome = self.cancelling_scope_children_afterwards(|this| CoroutineInstance::start(coroutine_instance_pointer, &mut this.coroutine_instance_allocator, this.global_allocator, this.watchdog_describer, &mut this.metrics, &mut this.lifecycle_hooks, start_arguments));
+					started(self, coroutine_instance_handle, start_outcome)
+				}
+				
+				Cancel(coroutine_instance_handle) => if let Some(coroutine_instance_pointer) = self.live_coroutine_instance_pointer(coroutine_instance_handle)
+				{
+					self.cancel_coroutine(coroutine_instance_pointer)
+				}
+			}
+		}
+	}
+	
+	/// Processes a timer that expired on a `TimerWheel`.
+	///
+	/// Timers for coroutines that are no longer live are discarded (returns `None`).
+	/// A `TimerPurpose::LifetimeDeadline` timer cancels its coroutine (returns `None`).
+	/// A `TimerPurpose::Wake` timer returns `Some()`; the caller should then resume the coroutine.
+	#[inline(always)]
+	pub fn expired_timer(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, purpose: TimerPurpose) -> Option<CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>>
+	{
+		let coroutine_instance_pointer = self.live_coroutine_instance_pointer(coroutine_instance_handle)?;
+		
+		use self::TimerPurpose::*;
+		
+		match purpose
+		{
+			Wake => Some(coroutine_instance_pointer),
+			
+			LifetimeDeadline =>
+			{
+				self.cancel_coroutine(coroutine_instance_pointer);
+				None
+			}
+		}
+	}
+	
+	/// Describes the memory of this coroutine manager as buffers suitable for registration with io_uring using `IORING_REGISTER_BUFFERS`.
+	///
+	/// The result must not outlive `self`; use `register_io_uring_fixed_buffers()` to describe and register in one step.
+	#[inline(always)]
+	pub fn io_uring_fixed_buffers(&self, registration: IoUringFixedBuffersRegistration) -> Result<IoUringFixedBuffers, IoUringFixedBuffersError>
+	{
+		self.coroutine_instance_allocator.io_uring_fixed_buffers(registration)
+	}
+	
+	/// Describes the memory of this coroutine manager as buffers and registers them with the io_uring `io_uring_file_descriptor` using `IORING_REGISTER_BUFFERS`.
+	///
+	/// The buffers must be unregistered (or the io_uring dropped) before `self` is dropped.
+	#[inline(always)]
+	pub fn register_io_uring_fixed_buffers(&self, registration: IoUringFixedBuffersRegistration, io_uring_file_descriptor: RawFd) -> Result<IoUringFixedBuffers, IoUringFixedBuffersError>
+	{
+		let io_uring_fixed_buffers = self.io_uring_fixed_buffers(registration)?;
+		io_uring_fixed_buffers.register(io_uring_file_descriptor).map_err(|error| IoUringFixedBuffersError::Registration { errno: error.raw_os_error().unwrap_or(0) })?;
+		Ok(io_uring_fixed_buffers)
+	}
+	
+	#[inline(always)]
+	fn new_coroutine_instance(&mut self, coroutine_information: CoroutineInformation, preemption_budget: Option<PreemptionBudget>) -> Result<CoroutineInstancePointer<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation>, AllocError>
+	{
+		let coroutine_instance_pointer = match self.coroutine_instance_allocator.new_coroutine_instance(self.index, self.global_allocator, coroutine_information)
+		{
+			Ok(coroutine_instance_pointer) => coroutine_instance_pointer,
+			
+			Err(AllocError) =>
+			{
+				self.metrics.allocation_failed();
+				return Err(AllocError)
+			}
+		};
+		
+		let coroutine_instance = unsafe { coroutine_instance_pointer.as_mut_unchecked(&self.coroutine_instance_allocator) };
+		coroutine_instance.preemption_budget = preemption_budget;
+		coroutine_instance.coroutine_panic_describer = self.coroutine_panic_describer;
+		
+		#[cfg(feature = "tracing")]
+		{
+			coroutine_instance.span = Some(self.span_factory.create(coroutine_instance_pointer.as_coroutine_instance_handle(), &coroutine_instance.coroutine_information));
+		}
+		
+		Ok(coroutine_instance_pointer)
+	}
+	
 	#[cfg(debug_assertions)]
 	#[doc(hidden)]
 	#[inline(always)]
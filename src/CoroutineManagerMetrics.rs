@@ -0,0 +1,85 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Counters maintained by a `CoroutineManager`.
+///
+/// Cycles are measured using the time stamp counter (`rdtsc`).
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineManagerMetrics
+{
+	/// Coroutines started.
+	pub started: u64,
+	
+	/// Coroutines that completed without panicking.
+	pub completed: u64,
+	
+	/// Coroutines that panicked.
+	pub panicked: u64,
+	
+	/// Coroutines cancelled (killed).
+	pub cancelled: u64,
+	
+	/// Coroutines currently live (started but not yet completed, panicked or cancelled).
+	pub live: u64,
+	
+	/// Highest value `live` has reached.
+	pub peak_live: u64,
+	
+	/// Coroutines not started because there was no coroutine instance free.
+	pub allocation_failures: u64,
+	
+	/// Times control has been transferred to a coroutine, including when started.
+	pub resumes: u64,
+	
+	/// Total cycles spent running coroutines.
+	pub on_cpu_cycles: u64,
+}
+
+impl CoroutineManagerMetrics
+{
+	#[inline(always)]
+	fn started(&mut self)
+	{
+		self.started += 1;
+		self.live += 1;
+		if self.live > self.peak_live
+		{
+			self.peak_live = self.live
+		}
+	}
+	
+	#[inline(always)]
+	fn finished(&mut self, panicked: bool)
+	{
+		if panicked
+		{
+			self.panicked += 1
+		}
+		else
+		{
+			self.completed += 1
+		}
+		self.live -= 1
+	}
+	
+	#[inline(always)]
+	fn cancelled(&mut self)
+	{
+		self.cancelled += 1;
+		self.live -= 1
+	}
+	
+	#[inline(always)]
+	fn allocation_failed(&mut self)
+	{
+		self.allocation_failures += 1
+	}
+	
+	#[inline(always)]
+	fn resumed(&mut self, on_cpu_cycles: u64)
+	{
+		self.resumes += 1;
+		self.on_cpu_cycles += on_cpu_cycles
+	}
+}
@@ -0,0 +1,49 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A snapshot of the metrics of a `CoroutineManager`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CoroutineManagerMetricsSnapshot
+{
+	/// Index of the coroutine manager.
+	pub coroutine_manager_index: CoroutineManagerIndex,
+	
+	/// Number of coroutine instances that can be live at once.
+	pub capacity: u64,
+	
+	/// Counters.
+	pub metrics: CoroutineManagerMetrics,
+}
+
+impl CoroutineManagerMetricsSnapshot
+{
+	/// Writes `snapshots` in the Prometheus text exposition format, eg for a scrape endpoint.
+	///
+	/// Each sample is labelled with `coroutine_manager_index`.
+	pub fn write_prometheus_text(snapshots: &[Self], writer: &mut impl Write) -> io::Result<()>
+	{
+		Self::write_prometheus_metric(snapshots, writer, "capacity", "gauge", "Coroutine instances that can be live at once.", |snapshot| snapshot.capacity)?;
+		Self::write_prometheus_metric(snapshots, writer, "started_total", "counter", "Coroutines started.", |snapshot| snapshot.metrics.started)?;
+		Self::write_prometheus_metric(snapshots, writer, "completed_total", "counter", "Coroutines that completed without panicking.", |snapshot| snapshot.metrics.completed)?;
+		Self::write_prometheus_metric(snapshots, writer, "panicked_total", "counter", "Coroutines that panicked.", |snapshot| snapshot.metrics.panicked)?;
+		Self::write_prometheus_metric(snapshots, writer, "cancelled_total", "counter", "Coroutines cancelled.", |snapshot| snapshot.metrics.cancelled)?;
+		Self::write_prometheus_metric(snapshots, writer, "live", "gauge", "Coroutines currently live.", |snapshot| snapshot.metrics.live)?;
+		Self::write_prometheus_metric(snapshots, writer, "peak_live", "gauge", "Highest number of coroutines live at once.", |snapshot| snapshot.metrics.peak_live)?;
+		Self::write_prometheus_metric(snapshots, writer, "allocation_failures_total", "counter", "Coroutines not started because there was no coroutine instance free.", |snapshot| snapshot.metrics.allocation_failures)?;
+		Self::write_prometheus_metric(snapshots, writer, "resumes_total", "counter", "Times control has been transferred to a coroutine, including when started.", |snapshot| snapshot.metrics.resumes)?;
+		Self::write_prometheus_metric(snapshots, writer, "on_cpu_cycles_total", "counter", "Time stamp counter cycles spent running coroutines.", |snapshot| snapshot.metrics.on_cpu_cycles)
+	}
+	
+	#[inline(always)]
+	fn write_prometheus_metric(snapshots: &[Self], writer: &mut impl Write, name: &str, metric_type: &str, help: &str, value: impl Fn(&Self) -> u64) -> io::Result<()>
+	{
+		writeln!(writer, "# HELP context_coroutine_{} {}", name, help)?;
+		writeln!(writer, "# TYPE context_coroutine_{} {}", name, metric_type)?;
+		for snapshot in snapshots
+		{
+			writeln!(writer, "context_coroutine_{}{{coroutine_manager_index=\"{}\"}} {}", name, snapshot.coroutine_manager_index.0, value(snapshot))?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,94 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// The panic payload of a coroutine that panicked, identifying the coroutine.
+///
+/// Unwinding resumes with this payload when a coroutine started or resumed by a `CoroutineManager` panics; it is also passed to `CoroutineLifecycleHooks::on_panic()` and delivered to a `JoinHandle`.
+/// If a coroutine panics because a coroutine it started or resumed panicked, the payload identifies the innermost coroutine.
+#[derive(Debug)]
+pub struct CoroutinePanic
+{
+	/// Handle of the coroutine that panicked.
+	pub coroutine_instance_handle: CoroutineInstanceHandle,
+	
+	/// Index of the coroutine manager of the coroutine that panicked.
+	pub coroutine_manager_index: CoroutineManagerIndex,
+	
+	/// Description of the coroutine's `CoroutineInformation`, if a `CoroutinePanicDescriber` was set, eg to render it using `Debug`.
+	pub coroutine_information: Option<String>,
+	
+	/// Captured on the coroutine's stack if `install_coroutine_panic_hook()` has been called; otherwise disabled.
+	pub backtrace: Backtrace,
+	
+	/// The original panic payload.
+	pub payload: Box<dyn Any + Send + 'static>,
+}
+
+impl Display for CoroutinePanic
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "coroutine {:?} of coroutine manager {} panicked", self.coroutine_instance_handle, self.coroutine_manager_index.0)?;
+		
+		if let Some(ref coroutine_information) = self.coroutine_information
+		{
+			write!(f, " ({})", coroutine_information)?;
+		}
+		
+		if let Some(message) = self.message()
+		{
+			write!(f, ": {}", message)?;
+		}
+		
+		if self.backtrace.status() == BacktraceStatus::Captured
+		{
+			write!(f, "\n{}", self.backtrace)?;
+		}
+		Ok(())
+	}
+}
+
+impl CoroutinePanic
+{
+	/// The panic message, if the original panic payload was a string.
+	#[inline(always)]
+	pub fn message(&self) -> Option<&str>
+	{
+		if let Some(message) = self.payload.downcast_ref::<&'static str>()
+		{
+			Some(message)
+		}
+		else if let Some(message) = self.payload.downcast_ref::<String>()
+		{
+			Some(message)
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// Wraps `payload` unless it is already a `CoroutinePanic` (of a coroutine the panicking coroutine started or resumed).
+	#[inline(always)]
+	fn wrap<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, CoroutineInformation: Sized>(payload: Box<dyn Any + Send + 'static>, global_allocator: &'static GTACSA, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation, coroutine_panic_describer: Option<CoroutinePanicDescriber<CoroutineInformation>>) -> Box<dyn Any + Send + 'static>
+	{
+		if payload.is::<Self>()
+		{
+			return payload
+		}
+		
+		Box::new
+		(
+			Self
+			{
+				coroutine_instance_handle,
+				coroutine_manager_index: coroutine_instance_handle.coroutine_manager_index(),
+				coroutine_information: coroutine_panic_describer.map(|coroutine_panic_describer| coroutine_panic_describer.description(coroutine_information)),
+				backtrace: take_coroutine_panic_backtrace(global_allocator, coroutine_instance_handle),
+				payload,
+			}
+		)
+	}
+}
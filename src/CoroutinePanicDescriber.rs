@@ -0,0 +1,57 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Describes the `CoroutineInformation` of a coroutine that panicked, for `CoroutinePanic.coroutine_information`; set using `CoroutineManager::set_coroutine_panic_describer()`.
+///
+/// Called on the coroutine's own thread once the coroutine has finished unwinding.
+pub struct CoroutinePanicDescriber<CoroutineInformation: Sized>(fn(&CoroutineInformation, &mut Formatter) -> fmt::Result);
+
+impl<CoroutineInformation: Sized> Debug for CoroutinePanicDescriber<CoroutineInformation>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutinePanicDescriber({:p})", self.0 as *const ())
+	}
+}
+
+impl<CoroutineInformation: Sized> Clone for CoroutinePanicDescriber<CoroutineInformation>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		*self
+	}
+}
+
+impl<CoroutineInformation: Sized> Copy for CoroutinePanicDescriber<CoroutineInformation>
+{
+}
+
+impl<CoroutineInformation: Sized> CoroutinePanicDescriber<CoroutineInformation>
+{
+	/// New instance.
+	#[inline(always)]
+	pub const fn new(describe: fn(&CoroutineInformation, &mut Formatter) -> fmt::Result) -> Self
+	{
+		Self(describe)
+	}
+	
+	#[inline(always)]
+	fn description(self, coroutine_information: &CoroutineInformation) -> String
+	{
+		struct Describe<'a, CoroutineInformation: Sized>(fn(&CoroutineInformation, &mut Formatter) -> fmt::Result, &'a CoroutineInformation);
+		
+		impl<'a, CoroutineInformation: Sized> Display for Describe<'a, CoroutineInformation>
+		{
+			#[inline(always)]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result
+			{
+				(self.0)(self.1, f)
+			}
+		}
+		
+		Describe(self.0, coroutine_information).to_string()
+	}
+}
@@ -0,0 +1,56 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Creates the `tracing` span for a coroutine instance when it is started; set using `CoroutineManager::set_span_factory()`.
+///
+/// Use this to record fields from `CoroutineInformation` on the span.
+pub struct CoroutineSpanFactory<CoroutineInformation: Sized>(fn(CoroutineInstanceHandle, &CoroutineInformation) -> Span);
+
+impl<CoroutineInformation: Sized> Debug for CoroutineSpanFactory<CoroutineInformation>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutineSpanFactory({:p})", self.0 as *const ())
+	}
+}
+
+impl<CoroutineInformation: Sized> Clone for CoroutineSpanFactory<CoroutineInformation>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		*self
+	}
+}
+
+impl<CoroutineInformation: Sized> Copy for CoroutineSpanFactory<CoroutineInformation>
+{
+}
+
+impl<CoroutineInformation: Sized> Default for CoroutineSpanFactory<CoroutineInformation>
+{
+	/// Creates an `INFO` span named `coroutine` with the field `coroutine_instance_handle`.
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self(|coroutine_instance_handle, _coroutine_information| info_span!("coroutine", coroutine_instance_handle = coroutine_instance_handle.unwrap()))
+	}
+}
+
+impl<CoroutineInformation: Sized> CoroutineSpanFactory<CoroutineInformation>
+{
+	/// New instance.
+	#[inline(always)]
+	pub const fn new(create: fn(CoroutineInstanceHandle, &CoroutineInformation) -> Span) -> Self
+	{
+		Self(create)
+	}
+	
+	#[inline(always)]
+	fn create(self, coroutine_instance_handle: CoroutineInstanceHandle, coroutine_information: &CoroutineInformation) -> Span
+	{
+		(self.0)(coroutine_instance_handle, coroutine_information)
+	}
+}
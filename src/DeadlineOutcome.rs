@@ -0,0 +1,14 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Outcome of `Yielder::yields_with_deadline()`.
+#[derive(Debug)]
+pub enum DeadlineOutcome<ResumeArguments: Sized>
+{
+	/// Coroutine was resumed before the deadline.
+	Resumed(ResumeArguments),
+	
+	/// Coroutine was resumed at or after the deadline, typically because the timer expired.
+	DeadlineExpired(ResumeArguments),
+}
@@ -0,0 +1,28 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A cycle of coroutines, each waiting on a primitive held by the next.
+///
+/// Returned by `RunQueue::detect_deadlock()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deadlock(Vec<(CoroutineInstanceHandle, WaitingOn)>);
+
+impl Deadlock
+{
+	/// Coroutines in the cycle and what each is waiting on.
+	#[inline(always)]
+	pub fn cycle(&self) -> &[(CoroutineInstanceHandle, WaitingOn)]
+	{
+		&self.0[..]
+	}
+	
+	/// Coroutines in the cycle with their coroutine information (if the coroutine is still live) and what each is waiting on.
+	///
+	/// Use `CoroutineManager::live_coroutine_instance_pointer()` and `CoroutineManager::coroutine_information()` to implement `coroutine_information`.
+	#[inline(always)]
+	pub fn describe<'a, CoroutineInformation: 'a>(&self, mut coroutine_information: impl FnMut(CoroutineInstanceHandle) -> Option<&'a CoroutineInformation>) -> Vec<(CoroutineInstanceHandle, Option<&'a CoroutineInformation>, WaitingOn)>
+	{
+		self.0.iter().map(|&(coroutine_instance_handle, waiting_on)| (coroutine_instance_handle, coroutine_information(coroutine_instance_handle), waiting_on)).collect()
+	}
+}
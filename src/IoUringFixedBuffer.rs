@@ -0,0 +1,50 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A fixed buffer.
+///
+/// Has exactly the same layout as `struct iovec` so that a slice of these can be passed to `io_uring_register(IORING_REGISTER_BUFFERS)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct IoUringFixedBuffer
+{
+	iov_base: NonNull<u8>,
+	iov_len: usize,
+}
+
+impl IoUringFixedBuffer
+{
+	#[inline(always)]
+	fn new(iov_base: usize, iov_len: usize) -> Self
+	{
+		Self
+		{
+			iov_base: new_non_null(iov_base as *mut u8),
+			iov_len,
+		}
+	}
+	
+	/// Start address.
+	///
+	/// This is the address to use in a submission queue entry for `IORING_OP_READ_FIXED` or `IORING_OP_WRITE_FIXED` when using the whole buffer.
+	#[inline(always)]
+	pub const fn address(self) -> NonNull<u8>
+	{
+		self.iov_base
+	}
+	
+	/// Length in bytes.
+	#[inline(always)]
+	pub const fn length(self) -> usize
+	{
+		self.iov_len
+	}
+	
+	#[inline(always)]
+	fn contains(self, address: usize) -> bool
+	{
+		let start = self.iov_base.as_ptr() as usize;
+		address >= start && address < (start + self.iov_len)
+	}
+}
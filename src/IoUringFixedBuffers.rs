@@ -0,0 +1,190 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A description of the memory of a `CoroutineManager` as buffers suitable for registration with io_uring using `IORING_REGISTER_BUFFERS`.
+///
+/// Once registered, coroutines can issue `IORING_OP_READ_FIXED` and `IORING_OP_WRITE_FIXED` for memory in their own heap without the kernel pinning pages for each operation.
+///
+/// The buffers refer to memory owned by the `CoroutineManager`; they must be unregistered (or the io_uring dropped) before the `CoroutineManager` is dropped.
+///
+/// Use `register()` to register them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IoUringFixedBuffers
+{
+	buffers: Vec<IoUringFixedBuffer>,
+	registration: IoUringFixedBuffersRegistration,
+	
+	base_address: usize,
+	coroutine_instance_size: usize,
+	heap_offset: usize,
+	heap_size: usize,
+	coroutine_instances_per_buffer: usize,
+}
+
+impl IoUringFixedBuffers
+{
+	/// `IORING_MAX_REG_BUFFERS`.
+	pub const MaximumNumberOfBuffers: usize = 1 << 14;
+	
+	/// The kernel refuses to register a buffer larger than 1Gb.
+	pub const MaximumBufferSize: usize = 1 << 30;
+	
+	#[inline(always)]
+	fn new(registration: IoUringFixedBuffersRegistration, base_address: usize, number_of_coroutine_instances: usize, coroutine_instance_size: usize, heap_offset: usize, heap_size: usize) -> Result<Self, IoUringFixedBuffersError>
+	{
+		use self::IoUringFixedBuffersError::*;
+		use self::IoUringFixedBuffersRegistration::*;
+		
+		let (buffer_size, coroutine_instances_per_buffer) = match registration
+		{
+			WholeRegion =>
+			{
+				if unlikely!(coroutine_instance_size > Self::MaximumBufferSize)
+				{
+					return Err(BufferTooLarge { size: coroutine_instance_size })
+				}
+				let coroutine_instances_per_buffer = Self::MaximumBufferSize / coroutine_instance_size;
+				(coroutine_instances_per_buffer * coroutine_instance_size, coroutine_instances_per_buffer)
+			}
+			
+			HeapsOnly =>
+			{
+				if unlikely!(heap_size > Self::MaximumBufferSize)
+				{
+					return Err(BufferTooLarge { size: heap_size })
+				}
+				(heap_size, 1)
+			}
+		};
+		
+		let number_of_buffers = (number_of_coroutine_instances + coroutine_instances_per_buffer - 1) / coroutine_instances_per_buffer;
+		if unlikely!(number_of_buffers > Self::MaximumNumberOfBuffers)
+		{
+			return Err(TooManyBuffers { number_of_buffers })
+		}
+		
+		let mut buffers = Vec::with_capacity(number_of_buffers);
+		match registration
+		{
+			WholeRegion =>
+			{
+				let region_size = number_of_coroutine_instances * coroutine_instance_size;
+				let mut offset = 0;
+				while offset < region_size
+				{
+					let length = min(buffer_size, region_size - offset);
+					buffers.push(IoUringFixedBuffer::new(base_address + offset, length));
+					offset += length;
+				}
+			}
+			
+			HeapsOnly => for index in 0 .. number_of_coroutine_instances
+			{
+				buffers.push(IoUringFixedBuffer::new(base_address + index * coroutine_instance_size + heap_offset, buffer_size));
+			}
+		}
+		
+		Ok
+		(
+			Self
+			{
+				buffers,
+				registration,
+				base_address,
+				coroutine_instance_size,
+				heap_offset,
+				heap_size,
+				coroutine_instances_per_buffer,
+			}
+		)
+	}
+	
+	/// Registers these buffers with an io_uring using `io_uring_register(IORING_REGISTER_BUFFERS)`.
+	///
+	/// Fails with `EBUSY` if the io_uring already has buffers registered.
+	#[inline(always)]
+	pub fn register(&self, io_uring_file_descriptor: RawFd) -> io::Result<()>
+	{
+		Self::io_uring_register(io_uring_file_descriptor, Self::IORING_REGISTER_BUFFERS, self.buffers.as_ptr() as *const c_void, self.buffers.len() as u32)
+	}
+	
+	/// Unregisters buffers previously registered with an io_uring using `io_uring_register(IORING_UNREGISTER_BUFFERS)`.
+	#[inline(always)]
+	pub fn unregister(io_uring_file_descriptor: RawFd) -> io::Result<()>
+	{
+		Self::io_uring_register(io_uring_file_descriptor, Self::IORING_UNREGISTER_BUFFERS, null(), 0)
+	}
+	
+	const IORING_REGISTER_BUFFERS: u32 = 0;
+	
+	const IORING_UNREGISTER_BUFFERS: u32 = 1;
+	
+	#[inline(always)]
+	fn io_uring_register(io_uring_file_descriptor: RawFd, opcode: u32, arg: *const c_void, nr_args: u32) -> io::Result<()>
+	{
+		let result = unsafe { syscall(SYS_io_uring_register, io_uring_file_descriptor, opcode, arg, nr_args) };
+		if likely!(result == 0)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(io::Error::last_os_error())
+		}
+	}
+	
+	/// How these buffers were described.
+	#[inline(always)]
+	pub fn registration(&self) -> IoUringFixedBuffersRegistration
+	{
+		self.registration
+	}
+	
+	/// Buffers to pass to `io_uring_register(IORING_REGISTER_BUFFERS)`.
+	///
+	/// The slice has the same layout as an array of `struct iovec`.
+	#[inline(always)]
+	pub fn buffers(&self) -> &[IoUringFixedBuffer]
+	{
+		&self.buffers[..]
+	}
+	
+	/// The `buf_index` to use in a submission queue entry and the heap of the coroutine instance.
+	///
+	/// A coroutine can use this with its own `CoroutineInstanceHandle` to find its heap.
+	///
+	/// Does not check the generation of `coroutine_instance_handle`.
+	#[inline(always)]
+	pub fn heap_of(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> (u16, IoUringFixedBuffer)
+	{
+		let relative_pointer = coroutine_instance_handle.relative_pointer_for_size(self.coroutine_instance_size);
+		let buffer_index = (relative_pointer / self.coroutine_instance_size) / self.coroutine_instances_per_buffer;
+		debug_assert!(buffer_index < self.buffers.len(), "coroutine_instance_handle is not for this coroutine manager");
+		
+		(buffer_index as u16, IoUringFixedBuffer::new(self.base_address + relative_pointer + self.heap_offset, self.heap_size))
+	}
+	
+	/// The `buf_index` to use in a submission queue entry for the memory `address .. address + length`.
+	///
+	/// Returns `None` if the memory is not wholly contained within a registered buffer.
+	#[inline(always)]
+	pub fn buffer_index_for(&self, address: NonNull<u8>, length: usize) -> Option<u16>
+	{
+		let address = address.as_ptr() as usize;
+		let last = address + length.saturating_sub(1);
+		
+		let coroutine_instance_index = address.checked_sub(self.base_address)? / self.coroutine_instance_size;
+		let buffer_index = coroutine_instance_index / self.coroutine_instances_per_buffer;
+		let buffer = *self.buffers.get(buffer_index)?;
+		
+		if buffer.contains(address) && buffer.contains(last)
+		{
+			Some(buffer_index as u16)
+		}
+		else
+		{
+			None
+		}
+	}
+}
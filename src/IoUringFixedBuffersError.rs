@@ -0,0 +1,42 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An error describing or registering coroutine instance memory as io_uring fixed buffers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IoUringFixedBuffersError
+{
+	/// More buffers would be needed than the kernel permits (`IORING_MAX_REG_BUFFERS`, 16,384).
+	TooManyBuffers
+	{
+		/// Number of buffers that would have been needed.
+		number_of_buffers: usize,
+	},
+	
+	/// A single coroutine instance (or its heap) is larger than the maximum size of a fixed buffer (1Gb).
+	BufferTooLarge
+	{
+		/// Size in bytes.
+		size: usize,
+	},
+	
+	/// `io_uring_register(IORING_REGISTER_BUFFERS)` failed.
+	Registration
+	{
+		/// `errno`.
+		errno: i32,
+	},
+}
+
+impl Display for IoUringFixedBuffersError
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		Debug::fmt(self, f)
+	}
+}
+
+impl error::Error for IoUringFixedBuffersError
+{
+}
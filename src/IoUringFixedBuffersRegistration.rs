@@ -0,0 +1,18 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Which parts of the memory of a `CoroutineManager` should be described as io_uring fixed buffers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IoUringFixedBuffersRegistration
+{
+	/// The entire contiguous region containing every coroutine instance (heaps, stacks and bookkeeping).
+	///
+	/// The region is split into as few buffers as possible; no buffer exceeds 1Gb and no coroutine instance straddles two buffers.
+	WholeRegion,
+	
+	/// One buffer per coroutine instance heap.
+	///
+	/// Limited by the kernel to 16,384 coroutine instances.
+	HeapsOnly,
+}
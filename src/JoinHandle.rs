@@ -0,0 +1,58 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Allows a coroutine to wait for another coroutine to finish and to receive how it finished.
+///
+/// Obtain using `CoroutineManager::join_handle()`.
+/// Once a coroutine has a join handle, its parent no longer receives its `Complete` value or its panic; instead, `StartOutcome::Joined` or `ResumeOutcome::Joined` is returned.
+/// If the join handle is dropped before the coroutine finishes, its parent receives them as if it never had a join handle.
+///
+/// A `Complete` value which owns memory must allocate it outside of the coroutine-local heap (eg using `RunQueue::outside_coroutine_local_heap()`), as that heap is recycled when the coroutine finishes.
+#[derive(Debug)]
+pub struct JoinHandle<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(Rc<JoinState<Complete, CoroutineHeapSize, GTACSA>>);
+
+impl<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> JoinHandle<Complete, CoroutineHeapSize, GTACSA>
+{
+	/// Suspends the coroutine of `yielder` until the joined coroutine has finished.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	///
+	/// If killed, `self` is returned with `kill_error` so that joining can be tried again.
+	#[inline(always)]
+	pub fn join<ResumeArguments, Yields, Complete2, E>(self, yielder: &mut Yielder<ResumeArguments, Yields, Complete2>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<JoinOutcome<Complete>, (Self, E)>
+	{
+		loop
+		{
+			if let Some(outcome) = self.0.slot.borrow_mut().outcome.take()
+			{
+				return Ok(outcome)
+			}
+			
+			if !yielder.wait(&self.0.run_queue, WaitingOn::Join(self.0.resource()), &self.0.slot, |slot| &mut slot.waiting, yields())
+			{
+				return Err((self, kill_error))
+			}
+		}
+	}
+	
+	/// Returns how the joined coroutine finished without suspending; if it has not yet finished, `self` is returned.
+	#[inline(always)]
+	pub fn try_join(self) -> Result<JoinOutcome<Complete>, Self>
+	{
+		let outcome = self.0.slot.borrow_mut().outcome.take();
+		match outcome
+		{
+			Some(outcome) => Ok(outcome),
+			
+			None => Err(self),
+		}
+	}
+	
+	/// Has the joined coroutine finished?
+	#[inline(always)]
+	pub fn is_finished(&self) -> bool
+	{
+		self.0.slot.borrow().outcome.is_some()
+	}
+}
@@ -0,0 +1,19 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// How a joined coroutine finished.
+#[derive(Debug)]
+pub enum JoinOutcome<Complete: Sized>
+{
+	/// Coroutine completed.
+	Complete(Complete),
+	
+	/// Coroutine panicked.
+	///
+	/// The payload is a copy made outside of the coroutine-local heap; payloads other than `&'static str` and `String` are replaced with a `&'static str` describing their loss.
+	Panicked(Box<dyn Any + Send + 'static>),
+	
+	/// Coroutine was cancelled (killed) before it completed.
+	Cancelled,
+}
@@ -0,0 +1,104 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Shared between a `JoinHandle` and the coroutine instance it joins.
+#[derive(Debug)]
+struct JoinState<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	slot: RefCell<JoinSlot<Complete>>,
+}
+
+impl<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> JoinState<Complete, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					slot: RefCell::new
+					(
+						JoinSlot
+						{
+							outcome: None,
+							waiting: WaitQueue::default(),
+						}
+					),
+				}
+			)
+		})
+	}
+	
+	#[inline(always)]
+	fn resource(&self) -> usize
+	{
+		self as *const Self as usize
+	}
+	
+	/// Must be called before the coroutine-local heap of the finished coroutine is freed.
+	#[inline(always)]
+	fn finished(&self, thread_result: thread::Result<Complete>)
+	{
+		let outcome = match thread_result
+		{
+			Ok(complete) => JoinOutcome::Complete(complete),
+			
+			Err(panic_information) => JoinOutcome::Panicked(self.stash_panic_payload(panic_information)),
+		};
+		self.deliver(outcome)
+	}
+	
+	#[inline(always)]
+	fn cancelled(&self)
+	{
+		self.deliver(JoinOutcome::Cancelled)
+	}
+	
+	#[inline(always)]
+	fn deliver(&self, outcome: JoinOutcome<Complete>)
+	{
+		let mut slot = self.slot.borrow_mut();
+		debug_assert!(slot.outcome.is_none(), "Outcome already delivered");
+		slot.outcome = Some(outcome);
+		slot.waiting.wake_all(&self.run_queue)
+	}
+	
+	/// A panic payload is allocated by the panicking coroutine, so is likely to be in its coroutine-local heap.
+	#[inline(always)]
+	fn stash_panic_payload(&self, panic_information: Box<dyn Any + Send + 'static>) -> Box<dyn Any + Send + 'static>
+	{
+		self.run_queue.outside_coroutine_local_heap(|| Self::copy_panic_payload(panic_information))
+	}
+	
+	/// The original payload of a `CoroutinePanic` is copied; the rest of it is not allocated by the panicking coroutine.
+	fn copy_panic_payload(panic_information: Box<dyn Any + Send + 'static>) -> Box<dyn Any + Send + 'static>
+	{
+		match panic_information.downcast::<CoroutinePanic>()
+		{
+			Ok(mut coroutine_panic) =>
+			{
+				coroutine_panic.payload = Self::copy_panic_payload(coroutine_panic.payload);
+				coroutine_panic
+			}
+			
+			Err(panic_information) => if let Some(message) = panic_information.downcast_ref::<&'static str>()
+			{
+				Box::new(*message) as Box<dyn Any + Send + 'static>
+			}
+			else if let Some(message) = panic_information.downcast_ref::<String>()
+			{
+				Box::new(message.clone())
+			}
+			else
+			{
+				Box::new("panic payload of an unsupported type was discarded")
+			}
+		}
+	}
+}
@@ -0,0 +1,11 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Lifecycle hooks that do nothing; the default for a `CoroutineManager`.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoCoroutineLifecycleHooks;
+
+impl<C: Coroutine, CoroutineInformation: Sized> CoroutineLifecycleHooks<C, CoroutineInformation> for NoCoroutineLifecycleHooks
+{
+}
@@ -0,0 +1,67 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Counts of identical stacks of suspended (parked) coroutines, eg to build a flame graph of where coroutines are blocked.
+///
+/// Populate using `CoroutineManager::profile_parked_stacks()`, potentially for several coroutine managers, then use `write_folded()`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ParkedStackProfile
+{
+	/// Number of coroutines suspended with each stack of return addresses (innermost first).
+	pub stacks: HashMap<Vec<usize>, u64>,
+}
+
+impl ParkedStackProfile
+{
+	/// Writes stacks in the folded stack format used by `flamegraph.pl` and `inferno`: one line per stack, of semicolon-separated function names (outermost first), a space and a count.
+	///
+	/// Symbols are resolved once per distinct return address.
+	pub fn write_folded(&self, writer: &mut impl Write) -> io::Result<()>
+	{
+		let mut resolved: HashMap<usize, String> = HashMap::new();
+		
+		for (stack, count) in self.stacks.iter()
+		{
+			let mut first = true;
+			for &return_address in stack.iter().rev()
+			{
+				let folded_frame = resolved.entry(return_address).or_insert_with(|| Self::folded_frame(return_address));
+				if first
+				{
+					first = false
+				}
+				else
+				{
+					writer.write_all(b";")?;
+				}
+				writer.write_all(folded_frame.as_bytes())?;
+			}
+			writeln!(writer, " {}", count)?;
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn record(&mut self, stack: Vec<usize>)
+	{
+		*self.stacks.entry(stack).or_insert(0) += 1
+	}
+	
+	/// Names of the (possibly inlined) functions at `return_address`, outermost first.
+	#[inline(always)]
+	fn folded_frame(return_address: usize) -> String
+	{
+		let frame = CoroutineBacktraceFrame::resolve(return_address);
+		
+		let names = frame.symbols.iter().rev().filter_map(|symbol| symbol.name.as_deref()).map(|name| name.replace(';', ",")).collect::<Vec<_>>();
+		if names.is_empty()
+		{
+			format!("{:#x}", return_address)
+		}
+		else
+		{
+			names.join(";")
+		}
+	}
+}
@@ -0,0 +1,23 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// How much a coroutine may do each time it is started or resumed before `Yielder::consume_budget()` (or `Yielder::maybe_yield()`) yields back to the scheduler.
+///
+/// The budget is replenished every time the coroutine is started or resumed, except for a budget of resumptions, which is replenished only once exhausted.
+///
+/// Set a default for all coroutines with `CoroutineManager::set_preemption_budget()`, or for one coroutine with `CoroutineManager::start_coroutine_with_preemption_budget()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PreemptionBudget
+{
+	/// A number of operations, as counted by calls to `Yielder::consume_budget()`.
+	Operations(NonZeroU64),
+	
+	/// A number of Time Stamp Counter (TSC) cycles, measured from when control is transferred to the coroutine.
+	Cycles(NonZeroU64),
+	
+	/// A number of resumptions (including the start), counted since the coroutine last exhausted its budget.
+	///
+	/// Once this number is reached, the next call to `Yielder::consume_budget()` exhausts the budget (`operations` is ignored).
+	Resumptions(NonZeroU64),
+}
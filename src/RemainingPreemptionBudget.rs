@@ -0,0 +1,70 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// What is left of the `PreemptionBudget` of the currently running coroutine.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum RemainingPreemptionBudget
+{
+	Unlimited,
+	
+	Operations(u64),
+	
+	CyclesUntil(u64),
+	
+	ResumptionsExhausted(bool),
+}
+
+impl Default for RemainingPreemptionBudget
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		RemainingPreemptionBudget::Unlimited
+	}
+}
+
+impl RemainingPreemptionBudget
+{
+	/// Called when control is about to be transferred to a coroutine.
+	///
+	/// `resumptions` includes the resumption about to happen.
+	#[inline(always)]
+	fn replenished(preemption_budget: Option<PreemptionBudget>, resumptions: u64) -> Self
+	{
+		use self::PreemptionBudget::*;
+		
+		match preemption_budget
+		{
+			None => RemainingPreemptionBudget::Unlimited,
+			
+			Some(Operations(operations)) => RemainingPreemptionBudget::Operations(operations.get()),
+			
+			Some(Cycles(cycles)) => RemainingPreemptionBudget::CyclesUntil(time_stamp_counter().saturating_add(cycles.get())),
+			
+			Some(Resumptions(maximum)) => RemainingPreemptionBudget::ResumptionsExhausted(resumptions >= maximum.get()),
+		}
+	}
+	
+	/// Returns `true` if exhausted.
+	#[inline(always)]
+	fn consume(&mut self, operations: u64) -> bool
+	{
+		use self::RemainingPreemptionBudget::*;
+		
+		match *self
+		{
+			Unlimited => false,
+			
+			Operations(ref mut remaining) =>
+			{
+				*remaining = remaining.saturating_sub(operations);
+				*remaining == 0
+			}
+			
+			CyclesUntil(deadline) => time_stamp_counter() >= deadline,
+			
+			ResumptionsExhausted(exhausted) => exhausted,
+		}
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Restores the current allocator in use when dropped, even if unwinding from a panic.
+struct RestoreCurrentAllocatorInUse<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	global_allocator: &'static GTACSA,
+	previous_current_allocator_in_use: CurrentAllocatorInUse,
+	marker: PhantomData<CoroutineHeapSize>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for RestoreCurrentAllocatorInUse<CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.global_allocator.replace_current_allocator_in_use(self.previous_current_allocator_in_use);
+	}
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RestoreCurrentAllocatorInUse<CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn switch(global_allocator: &'static GTACSA, current_allocator_in_use: CurrentAllocatorInUse) -> Self
+	{
+		Self
+		{
+			global_allocator,
+			previous_current_allocator_in_use: global_allocator.replace_current_allocator_in_use(current_allocator_in_use),
+			marker: PhantomData,
+		}
+	}
+}
@@ -0,0 +1,139 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A queue of coroutines, on one thread, that have been woken and are ready to be resumed.
+///
+/// Coroutine-aware primitives, such as those in the `channels` module, push the handles of coroutines they wake onto a run queue.
+/// The owner of the run queue (typically an event loop) should `pop()` each handle, discard it if stale using `CoroutineManager::live_coroutine_instance_pointer()` and then resume the coroutine.
+///
+/// Optionally, maintains a wait-for graph so that deadlocks can be detected when the run queue is empty; see `detect_deadlock()`.
+///
+/// Also provides a way to allocate memory outside of the coroutine-local heap whilst a coroutine is running, so that memory shared between coroutines remains valid after the coroutine that allocated it has completed.
+#[derive(Debug)]
+pub struct RunQueue<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	global_allocator: &'static GTACSA,
+	ready: RefCell<VecDeque<CoroutineInstanceHandle>>,
+	wait_for_graph: Option<RefCell<WaitForGraph>>,
+	marker: PhantomData<CoroutineHeapSize>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RunQueue<CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	///
+	/// Maintaining a wait-for graph for `deadlock_detection` adds a cost to every wait, to every acquisition and release of a mutex, reader-writer lock or semaphore and to every use of a channel endpoint by a different coroutine to its last user.
+	#[inline(always)]
+	pub fn new(global_allocator: &'static GTACSA, deadlock_detection: bool) -> Rc<Self>
+	{
+		let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::ThreadLocal);
+		
+		Rc::new
+		(
+			Self
+			{
+				global_allocator,
+				ready: RefCell::new(VecDeque::new()),
+				wait_for_graph: if deadlock_detection
+				{
+					Some(RefCell::new(WaitForGraph::default()))
+				}
+				else
+				{
+					None
+				},
+				marker: PhantomData,
+			}
+		)
+	}
+	
+	/// Wakes a coroutine; it is added to the back of the queue.
+	#[inline(always)]
+	pub fn wake(&self, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		self.outside_coroutine_local_heap(|| self.ready.borrow_mut().push_back(coroutine_instance_handle))
+	}
+	
+	/// Takes the next coroutine to resume.
+	///
+	/// The handle may be stale.
+	#[inline(always)]
+	pub fn pop(&self) -> Option<CoroutineInstanceHandle>
+	{
+		self.ready.borrow_mut().pop_front()
+	}
+	
+	/// Are there no coroutines ready to be resumed?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.ready.borrow().is_empty()
+	}
+	
+	/// If the run queue is empty and deadlock detection is enabled, finds a cycle of coroutines each waiting on a mutex, reader-writer lock, semaphore or channel endpoint held by the next.
+	///
+	/// Call this when there is nothing left to resume, eg before waiting on a reactor with an infinite timeout.
+	#[inline(always)]
+	pub fn detect_deadlock(&self) -> Option<Deadlock>
+	{
+		if !self.is_empty()
+		{
+			return None
+		}
+		
+		self.wait_for_graph.as_ref().and_then(|wait_for_graph| self.outside_coroutine_local_heap(|| wait_for_graph.borrow().find_cycle()))
+	}
+	
+	/// Executes `callback` using the thread-local allocator rather than the coroutine-local allocator.
+	///
+	/// Memory allocated in `callback` can be freed later by any coroutine on this thread (or outside of a coroutine).
+	#[inline(always)]
+	pub fn outside_coroutine_local_heap<R>(&self, callback: impl FnOnce() -> R) -> R
+	{
+		let _restore = RestoreCurrentAllocatorInUse::switch(self.global_allocator, CurrentAllocatorInUse::ThreadLocal);
+		callback()
+	}
+	
+	#[inline(always)]
+	fn started_waiting(&self, coroutine_instance_handle: CoroutineInstanceHandle, waiting_on: WaitingOn)
+	{
+		self.update_wait_for_graph(|wait_for_graph| wait_for_graph.started_waiting(coroutine_instance_handle, waiting_on))
+	}
+	
+	#[inline(always)]
+	fn stopped_waiting(&self, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		self.update_wait_for_graph(|wait_for_graph| wait_for_graph.stopped_waiting(coroutine_instance_handle))
+	}
+	
+	/// `holder` is `None` if not acquired by a coroutine.
+	#[inline(always)]
+	fn acquired(&self, resource: usize, holder: Option<CoroutineInstanceHandle>)
+	{
+		if let Some(coroutine_instance_handle) = holder
+		{
+			self.update_wait_for_graph(|wait_for_graph| wait_for_graph.acquired(resource, coroutine_instance_handle))
+		}
+	}
+	
+	#[inline(always)]
+	fn released(&self, resource: usize, holder: Option<CoroutineInstanceHandle>)
+	{
+		if let Some(coroutine_instance_handle) = holder
+		{
+			self.update_wait_for_graph(|wait_for_graph| wait_for_graph.released(resource, coroutine_instance_handle))
+		}
+	}
+	
+	#[inline(always)]
+	fn update_wait_for_graph(&self, update: impl FnOnce(&mut WaitForGraph))
+	{
+		if let Some(ref wait_for_graph) = self.wait_for_graph
+		{
+			self.outside_coroutine_local_heap(|| update(&mut wait_for_graph.borrow_mut()))
+		}
+	}
+}
@@ -8,6 +8,12 @@ pub enum StartOutcome<Yields: Sized, Complete: Sized>
 	/// Coroutine has returned an intermediate result and would to resume.
 	WouldLikeToResume(Yields),
 
+	/// Coroutine has exhausted its `PreemptionBudget` and would like to resume; it should be requeued (eg using `RunQueue::wake()`).
+	BudgetExhausted,
+
 	/// Coroutine has completed.
 	Complete(Complete),
+	
+	/// Coroutine has completed, panicked or been cancelled; how it finished has been passed to its `JoinHandle`.
+	Joined,
 }
@@ -7,14 +7,15 @@
 /// The actual layout might change
 ///
 /// ```bo
-/// ┌──────────────┬──────────┬───────────┬────────────┬────────┐
-/// │      63      │ 62 …  52 │ 51  …  48 │ 47   …  24 │ 23 … 0 │
-/// ├──────────────┼──────────┼───────────┼────────────┼────────┤
-/// │ Is Coroutine │ Reserved │ User Bits │ Generation │ Index  │
-/// └──────────────┴──────────┴───────────┴────────────┴────────┘
+/// ┌──────────────┬──────────┬───────────────────────────┬───────────┬────────────┬────────┐
+/// │      63      │ 62 …  60 │          59 … 52          │ 51  …  48 │ 47   …  24 │ 23 … 0 │
+/// ├──────────────┼──────────┼───────────────────────────┼───────────┼────────────┼────────┤
+/// │ Is Coroutine │ Reserved │ Coroutine Manager Index   │ User Bits │ Generation │ Index  │
+/// └──────────────┴──────────┴───────────────────────────┴───────────┴────────────┴────────┘
 /// ```
 /// * `Is Coroutine`: if set (`1`), then bits `62 … 0` have meaning as defined above.
 /// * `Reserved`: bits reserved for future use or expansion
+/// * `Coroutine Manager Index`: The index of the `CoroutineManager` which owns the coroutine.
 /// * `User Bits`: Currently, 4 bits available for coroutine user state notification; always zero when a coroutine is passed this data.
 /// * `Generation`: A counter used to avoid the ABA problem when re-using memory for coroutines where the `Index` may have previously been used, eg in user data passed to epoll or io_uring.
 /// * `Index`: A relative index to a coroutine in memory.
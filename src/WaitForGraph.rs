@@ -0,0 +1,129 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Which coroutines are waiting on which primitives, and which coroutines hold those primitives.
+///
+/// Condition variables and wait groups are never held, so only waiting on a mutex, reader-writer lock, semaphore or channel can form part of a cycle.
+/// A channel endpoint is held by the last coroutine to use it (see `Channel::endpoint_used()`).
+#[derive(Debug, Default)]
+struct WaitForGraph
+{
+	waiting: HashMap<CoroutineInstanceHandle, WaitingOn>,
+	holders: HashMap<usize, Vec<CoroutineInstanceHandle>>,
+}
+
+impl WaitForGraph
+{
+	#[inline(always)]
+	fn started_waiting(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, waiting_on: WaitingOn)
+	{
+		self.waiting.insert(coroutine_instance_handle, waiting_on);
+	}
+	
+	#[inline(always)]
+	fn stopped_waiting(&mut self, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		self.waiting.remove(&coroutine_instance_handle);
+	}
+	
+	#[inline(always)]
+	fn acquired(&mut self, resource: usize, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		self.holders.entry(resource).or_default().push(coroutine_instance_handle)
+	}
+	
+	#[inline(always)]
+	fn released(&mut self, resource: usize, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		if let Some(holders) = self.holders.get_mut(&resource)
+		{
+			if let Some(index) = holders.iter().position(|holder| *holder == coroutine_instance_handle)
+			{
+				holders.swap_remove(index);
+			}
+			if holders.is_empty()
+			{
+				self.holders.remove(&resource);
+			}
+		}
+	}
+	
+	/// Depth-first search from every waiting coroutine along the edges 'waiter waits on a primitive held by holder'.
+	///
+	/// For channels, the edges are 'blocked receiver waits on every coroutine holding a sender' and 'blocked sender waits on every coroutine holding a receiver'.
+	/// A coroutine waiting on a channel whose other end it last used is not waiting on itself, as it may since have moved that endpoint to another coroutine, so such edges are ignored.
+	#[inline(always)]
+	fn find_cycle(&self) -> Option<Deadlock>
+	{
+		let mut finished = HashSet::with_capacity(self.waiting.len());
+		
+		for &start in self.waiting.keys()
+		{
+			if finished.contains(&start)
+			{
+				continue
+			}
+			
+			let mut path: Vec<CoroutineInstanceHandle> = Vec::new();
+			let mut stack: Vec<(CoroutineInstanceHandle, usize)> = vec![(start, 0)];
+			
+			while let Some(&mut (coroutine_instance_handle, ref mut next_holder_index)) = stack.last_mut()
+			{
+				if *next_holder_index == 0
+				{
+					path.push(coroutine_instance_handle);
+				}
+				
+				let holders = self.holders_of_what_is_waited_on_by(coroutine_instance_handle);
+				match holders.get(*next_holder_index)
+				{
+					None =>
+					{
+						stack.pop();
+						path.pop();
+						finished.insert(coroutine_instance_handle);
+					}
+					
+					Some(&holder) =>
+					{
+						*next_holder_index += 1;
+						
+						if holder == coroutine_instance_handle && self.waiting[&coroutine_instance_handle].is_for_a_channel()
+						{
+							continue
+						}
+						
+						if let Some(position) = path.iter().position(|on_path| *on_path == holder)
+						{
+							return Some(Deadlock(path[position ..].iter().map(|&in_cycle| (in_cycle, self.waiting[&in_cycle])).collect()))
+						}
+						
+						if self.waiting.contains_key(&holder) && !finished.contains(&holder)
+						{
+							stack.push((holder, 0))
+						}
+					}
+				}
+			}
+		}
+		
+		None
+	}
+	
+	#[inline(always)]
+	fn holders_of_what_is_waited_on_by(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> &[CoroutineInstanceHandle]
+	{
+		match self.waiting.get(&coroutine_instance_handle)
+		{
+			None => &[],
+			
+			Some(waiting_on) => match self.holders.get(&waiting_on.held_resource())
+			{
+				None => &[],
+				
+				Some(holders) => &holders[..],
+			}
+		}
+	}
+}
@@ -0,0 +1,62 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Coroutines waiting to be woken, in the order they started waiting.
+#[derive(Debug, Default)]
+struct WaitQueue(VecDeque<CoroutineInstanceHandle>);
+
+impl WaitQueue
+{
+	#[inline(always)]
+	fn push<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>, coroutine_instance_handle: CoroutineInstanceHandle)
+	{
+		run_queue.outside_coroutine_local_heap(|| self.0.push_back(coroutine_instance_handle))
+	}
+	
+	/// Returns `true` if `coroutine_instance_handle` was waiting.
+	#[inline(always)]
+	fn remove(&mut self, coroutine_instance_handle: CoroutineInstanceHandle) -> bool
+	{
+		match self.0.iter().position(|waiting| *waiting == coroutine_instance_handle)
+		{
+			None => false,
+			
+			Some(index) =>
+			{
+				self.0.remove(index);
+				true
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn is_empty(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Returns `true` if a coroutine was woken.
+	#[inline(always)]
+	fn wake_one<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>) -> bool
+	{
+		match self.0.pop_front()
+		{
+			None => false,
+			
+			Some(coroutine_instance_handle) =>
+			{
+				run_queue.wake(coroutine_instance_handle);
+				true
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn wake_all<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>)
+	{
+		while self.wake_one(run_queue)
+		{
+		}
+	}
+}
@@ -0,0 +1,97 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// What a coroutine is waiting on.
+///
+/// Each variant holds the address of the primitive, which identifies it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WaitingOn
+{
+	/// Waiting to send to a full channel.
+	ChannelSend(usize),
+	
+	/// Waiting to receive from an empty channel.
+	ChannelReceive(usize),
+	
+	/// Waiting to lock a `CoroutineMutex`.
+	Mutex(usize),
+	
+	/// Waiting to lock a `CoroutineRwLock` for reading.
+	RwLockRead(usize),
+	
+	/// Waiting to lock a `CoroutineRwLock` for writing.
+	RwLockWrite(usize),
+	
+	/// Waiting to acquire a permit from a `CoroutineSemaphore`.
+	Semaphore(usize),
+	
+	/// Waiting to be notified by a `CoroutineCondvar`.
+	Condvar(usize),
+	
+	/// Waiting for the count of a `WaitGroup` to reach zero.
+	WaitGroup(usize),
+	
+	/// Waiting for a coroutine to finish using a `JoinHandle`.
+	Join(usize),
+	
+	/// Waiting for the children of a `Scope` to finish.
+	Scope(usize),
+}
+
+impl WaitingOn
+{
+	/// Address of the primitive waited on.
+	#[inline(always)]
+	pub fn resource(self) -> usize
+	{
+		use self::WaitingOn::*;
+		
+		match self
+		{
+			ChannelSend(resource) => resource,
+			ChannelReceive(resource) => resource,
+			Mutex(resource) => resource,
+			RwLockRead(resource) => resource,
+			RwLockWrite(resource) => resource,
+			Semaphore(resource) => resource,
+			Condvar(resource) => resource,
+			WaitGroup(resource) => resource,
+			Join(resource) => resource,
+			Scope(resource) => resource,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_for_a_channel(self) -> bool
+	{
+		use self::WaitingOn::*;
+		
+		match self
+		{
+			ChannelSend(_) | ChannelReceive(_) => true,
+			
+			_ => false,
+		}
+	}
+	
+	/// The resource whose holders this waits on.
+	///
+	/// For channels, this is the endpoints at the other end: a sender waits on the coroutines holding a `Receiver` and a receiver waits on those holding a `Sender`.
+	#[inline(always)]
+	fn held_resource(self) -> usize
+	{
+		use self::WaitingOn::*;
+		
+		match self
+		{
+			// See `Channel::receivers_resource()`.
+			ChannelSend(resource) => resource + 1,
+			
+			// See `Channel::senders_resource()`.
+			ChannelReceive(resource) => resource,
+			
+			_ => self.resource(),
+		}
+	}
+}
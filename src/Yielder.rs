@@ -6,33 +6,271 @@
 #[derive(Debug)]
 pub struct Yielder<'yielder, ResumeArguments: 'yielder, Yields: 'yielder, Complete: 'yielder>
 {
-	type_safe_transfer: &'yielder mut TypeSafeTransfer<ParentInstructingChild<ResumeArguments>, ChildOutcome<Yields, Complete>>
+	coroutine_instance_handle: CoroutineInstanceHandle,
+	type_safe_transfer: &'yielder mut TypeSafeTransfer<ParentInstructingChild<ResumeArguments>, ChildOutcome<Yields, Complete>>,
+	killed: bool,
 }
 
 impl<'yielder, ResumeArguments: 'yielder, Yields: 'yielder, Complete: 'yielder> Yielder<'yielder, ResumeArguments, Yields, Complete>
 {
 	#[inline(always)]
-	fn new(type_safe_transfer: &'yielder mut TypeSafeTransfer<ParentInstructingChild<ResumeArguments>, ChildOutcome<Yields, Complete>>) -> Self
+	fn new(coroutine_instance_handle: CoroutineInstanceHandle, type_safe_transfer: &'yielder mut TypeSafeTransfer<ParentInstructingChild<ResumeArguments>, ChildOutcome<Yields, Complete>>) -> Self
 	{
 		Self
 		{
-			type_safe_transfer
+			coroutine_instance_handle,
+			type_safe_transfer,
+			killed: false,
 		}
 	}
+	
+	/// The handle of the coroutine this yielder yields from.
+	#[inline(always)]
+	pub fn coroutine_instance_handle(&self) -> CoroutineInstanceHandle
+	{
+		self.coroutine_instance_handle
+	}
+	
+	/// The `CoroutineInformation` of the coroutine this yielder yields from.
+	///
+	/// Returns `None` if `CoroutineInformation` is not the type used by the coroutine's `CoroutineManager`.
+	#[inline(always)]
+	pub fn coroutine_information<CoroutineInformation: 'static + Sized>(&self) -> Option<&CoroutineInformation>
+	{
+		unsafe { self.coroutine_instance_handle.coroutine_information() }
+	}
+	
+	/// The `CoroutineInformation` of the coroutine this yielder yields from.
+	///
+	/// Returns `None` if `CoroutineInformation` is not the type used by the coroutine's `CoroutineManager`.
+	#[inline(always)]
+	pub fn coroutine_information_mut<CoroutineInformation: 'static + Sized>(&mut self) -> Option<&mut CoroutineInformation>
+	{
+		unsafe { self.coroutine_instance_handle.coroutine_information_mut() }
+	}
 
 	/// Yields.
 	///
 	/// Returns either `Ok(resume_arguments)` or `Err(kill_error)`.
 	#[inline(always)]
 	pub fn yields<E>(&mut self, yields: Yields, kill_error: E) -> Result<ResumeArguments, E>
+	{
+		self.suspend(yields).ok_or(kill_error)
+	}
+	
+	/// Consumes `operations` from this coroutine's `PreemptionBudget` (a budget of cycles or of resumptions ignores `operations`).
+	///
+	/// If the budget is exhausted, yields back to the scheduler, which sees `ResumeOutcome::BudgetExhausted` (or `StartOutcome::BudgetExhausted`) and should requeue this coroutine; returns `Ok(Some(resume_arguments))` once resumed.
+	/// Otherwise returns `Ok(None)` without yielding.
+	///
+	/// Returns `Err(kill_error)` if killed.
+	/// Once killed, does nothing and returns `Ok(None)`, so that code run whilst unwinding is not interrupted.
+	#[inline(always)]
+	pub fn consume_budget<E>(&mut self, operations: u64, kill_error: E) -> Result<Option<ResumeArguments>, E>
+	{
+		if unlikely!(self.killed)
+		{
+			return Ok(None)
+		}
+		
+		if likely!(!consume_remaining_preemption_budget(operations))
+		{
+			return Ok(None)
+		}
+		
+		self.transfer_to_parent(ChildOutcome::BudgetExhausted).map(Some).ok_or(kill_error)
+	}
+	
+	/// Consumes one operation from this coroutine's `PreemptionBudget`.
+	///
+	/// Call this regularly in long-running loops.
+	///
+	/// See `consume_budget()`.
+	#[inline(always)]
+	pub fn maybe_yield<E>(&mut self, kill_error: E) -> Result<Option<ResumeArguments>, E>
+	{
+		self.consume_budget(1, kill_error)
+	}
+	
+	/// Executes `work` in a region where this coroutine may be preempted by this thread's `PreemptionTimer` if `work` runs for longer than the timer's slice.
+	///
+	/// Use this for third-party code that never yields.
+	/// When preempted, the scheduler sees `ResumeOutcome::BudgetExhausted` and should requeue this coroutine; resume arguments received are discarded.
+	///
+	/// Preemption only occurs whilst the coroutine-local allocator is in use and not inside an allocator operation (see `InAllocatorGuard`).
+	///
+	/// Returns `Err(kill_error)` if killed whilst preempted; `work` is first run to completion, without further preemption.
+	/// Killing a coroutine (eg cancelling it or dropping its `CoroutineManager`) whilst it is preempted therefore does not return until `work` does; if `work` never finishes, this thread hangs.
+	/// Once killed, `work` is run without preemption.
+	///
+	/// # Safety
+	///
+	/// `work` must not use state shared with other coroutines or the scheduler on this thread (eg `RefCell`s, thread-locals or standard out), as it may be interrupted part way through using it.
+	///
+	/// `GTACSA` must hold an `InAllocatorGuard` for the whole of every `alloc()`, `dealloc()` and `realloc()`, so the thread-local and global allocators are never interrupted part way through an operation.
+	#[inline(always)]
+	pub unsafe fn preemptible<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, R, E>(&mut self, global_allocator: &'static GTACSA, work: impl FnOnce() -> R, kill_error: E) -> Result<R, E>
+	{
+		if unlikely!(self.killed)
+		{
+			return Ok(work())
+		}
+		
+		let preemptible_region_guard = PreemptibleRegionGuard::enter(PreemptibleRegion::new(self as *mut Self as *mut (), Self::yield_to_parent_after_preemption, global_allocator));
+		let result = work();
+		if preemptible_region_guard.leave()
+		{
+			Err(kill_error)
+		}
+		else
+		{
+			Ok(result)
+		}
+	}
+	
+	/// Returns `false` if killed.
+	#[inline(always)]
+	unsafe fn yield_to_parent_after_preemption(yielder: *mut ()) -> bool
+	{
+		let this = &mut *(yielder as *mut Self);
+		if this.killed
+		{
+			return false
+		}
+		this.transfer_to_parent(ChildOutcome::BudgetExhausted).is_some()
+	}
+	
+	/// Yields until `deadline`.
+	///
+	/// A `TimerPurpose::Wake` timer is scheduled on `timer_wheel`; the scheduler should resume this coroutine when it expires.
+	/// If this coroutine is resumed before `deadline` it yields again; resume arguments received are discarded.
+	/// A `deadline` further away than `timer_wheel` can hold expires early; another timer is then scheduled.
+	/// `yields` is called to produce a value every time this coroutine yields.
+	///
+	/// Returns `Err(TimerError::Killed(kill_error))` if killed whilst sleeping, or `Err(TimerError::Full)` if `timer_wheel` is full.
+	#[inline(always)]
+	pub fn sleep_until<E>(&mut self, timer_wheel: &RefCell<TimerWheel>, deadline: Instant, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<(), TimerError<E>>
+	{
+		let mut timer_key = self.schedule_wake(timer_wheel, deadline)?;
+		
+		while Instant::now() < deadline
+		{
+			if self.suspend(yields()).is_none()
+			{
+				timer_wheel.borrow_mut().cancel(timer_key);
+				return Err(TimerError::Killed(kill_error))
+			}
+			
+			if !timer_wheel.borrow().is_scheduled(timer_key) && Instant::now() < deadline
+			{
+				timer_key = self.schedule_wake(timer_wheel, deadline)?;
+			}
+		}
+		
+		timer_wheel.borrow_mut().cancel(timer_key);
+		Ok(())
+	}
+	
+	/// Yields for at least `duration`.
+	///
+	/// See `sleep_until()`.
+	#[inline(always)]
+	pub fn sleep<E>(&mut self, timer_wheel: &RefCell<TimerWheel>, duration: Duration, yields: impl FnMut() -> Yields, kill_error: E) -> Result<(), TimerError<E>>
+	{
+		self.sleep_until(timer_wheel, Instant::now() + duration, yields, kill_error)
+	}
+	
+	/// Yields once, with a `TimerPurpose::Wake` timer scheduled on `timer_wheel` to resume this coroutine at `deadline` if nothing else does so first.
+	///
+	/// When resuming a coroutine because its timer expired, the scheduler passes resume arguments as usual; the outcome distinguishes this from being resumed before `deadline`.
+	///
+	/// Returns `Err(TimerError::Killed(kill_error))` if killed, or `Err(TimerError::Full)` if `timer_wheel` is full.
+	#[inline(always)]
+	pub fn yields_with_deadline<E>(&mut self, timer_wheel: &RefCell<TimerWheel>, deadline: Instant, yields: Yields, kill_error: E) -> Result<DeadlineOutcome<ResumeArguments>, TimerError<E>>
+	{
+		let timer_key = self.schedule_wake(timer_wheel, deadline)?;
+		let resumed = self.suspend(yields);
+		timer_wheel.borrow_mut().cancel(timer_key);
+		
+		let resume_arguments = resumed.ok_or(TimerError::Killed(kill_error))?;
+		
+		if Instant::now() < deadline
+		{
+			Ok(DeadlineOutcome::Resumed(resume_arguments))
+		}
+		else
+		{
+			Ok(DeadlineOutcome::DeadlineExpired(resume_arguments))
+		}
+	}
+	
+	#[inline(always)]
+	fn schedule_wake<E>(&self, timer_wheel: &RefCell<TimerWheel>, deadline: Instant) -> Result<TimerKey, TimerError<E>>
+	{
+		timer_wheel.borrow_mut().schedule(self.coroutine_instance_handle, deadline, TimerPurpose::Wake).map_err(|AllocError| TimerError::Full)
+	}
+	
+	/// Waits in a `WaitQueue` until woken; resume arguments received are discarded.
+	///
+	/// A coroutine resumed without having been woken (ie whilst still in the wait queue) is removed from the wait queue so it can try again.
+	/// A coroutine killed after being woken passes the wake on to the next waiter so that it is not lost.
+	///
+	/// Returns `false` if killed.
+	#[inline(always)]
+	fn wait<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, State>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>, waiting_on: WaitingOn, state: &RefCell<State>, wait_queue: impl Fn(&mut State) -> &mut WaitQueue, yields: Yields) -> bool
+	{
+		self.wait_passing_on_wake(run_queue, waiting_on, state, &wait_queue, |state| { wait_queue(state).wake_one(run_queue); }, yields)
+	}
+	
+	/// As `wait()`, but `pass_on_wake` decides who to wake if killed after being woken.
+	#[inline(always)]
+	fn wait_passing_on_wake<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, State>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>, waiting_on: WaitingOn, state: &RefCell<State>, wait_queue: impl Fn(&mut State) -> &mut WaitQueue, pass_on_wake: impl FnOnce(&mut State), yields: Yields) -> bool
+	{
+		let coroutine_instance_handle = self.coroutine_instance_handle;
+		
+		wait_queue(&mut state.borrow_mut()).push(run_queue, coroutine_instance_handle);
+		run_queue.started_waiting(coroutine_instance_handle, waiting_on);
+		let killed = self.suspend(yields).is_none();
+		run_queue.stopped_waiting(coroutine_instance_handle);
+		
+		let mut state = state.borrow_mut();
+		let was_woken = !wait_queue(&mut state).remove(coroutine_instance_handle);
+		if killed
+		{
+			if was_woken
+			{
+				pass_on_wake(&mut state);
+			}
+			false
+		}
+		else
+		{
+			true
+		}
+	}
+	
+	/// Returns `None` if killed.
+	#[inline(always)]
+	fn suspend(&mut self, yields: Yields) -> Option<ResumeArguments>
+	{
+		self.transfer_to_parent(ChildOutcome::WouldLikeToResume(yields))
+	}
+	
+	/// Returns `None` if killed.
+	#[inline(always)]
+	fn transfer_to_parent(&mut self, child_outcome: ChildOutcome<Yields, Complete>) -> Option<ResumeArguments>
 	{
 		use self::ParentInstructingChild::*;
 
-		match self.type_safe_transfer.resume_drop_safe(ChildOutcome::WouldLikeToResume(yields))
+		match self.type_safe_transfer.resume_drop_safe(child_outcome)
 		{
-			Resume(resume_arguments) => Ok(resume_arguments),
+			Resume(resume_arguments) => Some(resume_arguments),
 
-			Kill => Err(kill_error),
+			Kill =>
+			{
+				self.killed = true;
+				None
+			}
 		}
 	}
 }
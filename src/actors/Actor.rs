@@ -0,0 +1,39 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An actor: a coroutine which handles messages from its mailbox one at a time.
+///
+/// Run an actor using `spawn_actor()`.
+pub trait Actor<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>: Sized
+{
+	/// Type of messages sent to this actor.
+	type Message: Sized;
+	
+	/// Type of the arguments the actor is initially created with.
+	type StartArguments: Sized;
+	
+	/// Type of the final result from the actor.
+	type Complete: Sized;
+	
+	/// Hint as to how long this actor is expected to live for.
+	///
+	/// Used to inform the choice of heap memory allocator.
+	const LifetimeHint: LifetimeHint;
+	
+	/// Used to inform the configuration of the heap memory bit set allocator (if chosen).
+	const HeapMemoryAllocatorBlockSizeHint: NonZeroUsize;
+	
+	/// Creates the actor's state; called inside the actor's coroutine.
+	fn start(coroutine_instance_handle: CoroutineInstanceHandle, start_arguments: Self::StartArguments) -> Self;
+	
+	/// Handles a message.
+	///
+	/// `yielder` can be used to suspend, eg to make a request of another actor using `ActorRef::request()`.
+	///
+	/// Return `Some()` to stop the actor.
+	fn handle(&mut self, yielder: &mut Yielder<(), (), Self::Complete>, message: Self::Message) -> Option<Self::Complete>;
+	
+	/// Called when the actor stops because every `ActorRef` has been dropped or the actor has been killed.
+	fn stopped(self, actor_stopped: ActorStopped) -> Self::Complete;
+}
@@ -0,0 +1,46 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// The coroutine that runs an actor.
+///
+/// Actors do not yield values to, nor receive resume arguments from, their parent; they are resumed when woken through the run queue.
+#[derive(Debug)]
+pub struct ActorCoroutine<A: Actor<CoroutineHeapSize, GTACSA>, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(PhantomData<(A, CoroutineHeapSize, &'static GTACSA)>);
+
+impl<A: Actor<CoroutineHeapSize, GTACSA>, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Coroutine for ActorCoroutine<A, CoroutineHeapSize, GTACSA>
+{
+	type StartArguments = (A::StartArguments, Mailbox<A::Message, CoroutineHeapSize, GTACSA>);
+	
+	type ResumeArguments = ();
+	
+	type Yields = ();
+	
+	type Complete = A::Complete;
+	
+	const LifetimeHint: LifetimeHint = A::LifetimeHint;
+	
+	const HeapMemoryAllocatorBlockSizeHint: NonZeroUsize = A::HeapMemoryAllocatorBlockSizeHint;
+	
+	#[inline(always)]
+	fn coroutine<'yielder>(coroutine_instance_handle: CoroutineInstanceHandle, mut yielder: Yielder<'yielder, Self::ResumeArguments, Self::Yields, Self::Complete>, start_arguments: Self::StartArguments) -> Self::Complete
+	{
+		let (start_arguments, mailbox) = start_arguments;
+		let mut actor = A::start(coroutine_instance_handle, start_arguments);
+		
+		loop
+		{
+			match mailbox.0.receive(&mut yielder, || (), ())
+			{
+				Ok(message) => if let Some(complete) = actor.handle(&mut yielder, message)
+				{
+					return complete
+				},
+				
+				Err(ReceiveError::Disconnected) => return actor.stopped(ActorStopped::Disconnected),
+				
+				Err(ReceiveError::Killed(())) => return actor.stopped(ActorStopped::Killed),
+			}
+		}
+	}
+}
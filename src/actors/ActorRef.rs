@@ -0,0 +1,93 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A cloneable address of an actor.
+pub struct ActorRef<Message: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	coroutine_instance_handle: CoroutineInstanceHandle,
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	sender: Sender<Message, CoroutineHeapSize, GTACSA>,
+}
+
+impl<Message: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for ActorRef<Message, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "ActorRef({:?})", self.coroutine_instance_handle)
+	}
+}
+
+impl<Message: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Clone for ActorRef<Message, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			coroutine_instance_handle: self.coroutine_instance_handle,
+			run_queue: self.run_queue.clone(),
+			sender: self.sender.clone(),
+		}
+	}
+}
+
+impl<Message: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> ActorRef<Message, CoroutineHeapSize, GTACSA>
+{
+	/// Handle of the actor's coroutine.
+	#[inline(always)]
+	pub fn coroutine_instance_handle(&self) -> CoroutineInstanceHandle
+	{
+		self.coroutine_instance_handle
+	}
+	
+	/// Is the actor still live?
+	///
+	/// Checks the generation of the actor's coroutine instance.
+	#[inline(always)]
+	pub fn is_alive<A: Actor<CoroutineHeapSize, GTACSA, Message=Message>, StackSize: MemorySize, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<ActorCoroutine<A, CoroutineHeapSize, GTACSA>, CoroutineInformation>>(&self, coroutine_manager: &CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, ActorCoroutine<A, CoroutineHeapSize, GTACSA>, CoroutineInformation, LifecycleHooks>) -> bool
+	{
+		coroutine_manager.live_coroutine_instance_pointer(self.coroutine_instance_handle).is_some()
+	}
+	
+	/// Sends a message; never suspends.
+	///
+	/// The actor is woken through the run queue if it is waiting for a message.
+	/// The actor's coroutine is never resumed directly, so sending to a stopped actor is safe and returns `Err(ActorSendError::Dead(message))`.
+	#[inline(always)]
+	pub fn send(&self, message: Message) -> Result<(), ActorSendError<Message>>
+	{
+		match self.sender.try_send(message)
+		{
+			Ok(()) => Ok(()),
+			
+			Err(TrySendError::Disconnected(message)) => Err(ActorSendError::Dead(message)),
+			
+			Err(TrySendError::Full(_)) => unsafe { unreachable() },
+		}
+	}
+	
+	/// Sends a message created by `message` and suspends the coroutine of `yielder` until the actor replies using the `ReplyTo` passed to `message`.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn request<Reply: Sized, ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, message: impl FnOnce(ReplyTo<Reply, CoroutineHeapSize, GTACSA>) -> Message, yields: impl FnMut() -> Yields, kill_error: E) -> Result<Reply, RequestError<E>>
+	{
+		let (reply_sender, reply_receiver) = bounded_channel(&self.run_queue, new_non_zero_usize(1));
+		
+		if self.send(message(ReplyTo(reply_sender))).is_err()
+		{
+			return Err(RequestError::Dead)
+		}
+		
+		match reply_receiver.receive(yielder, yields, kill_error)
+		{
+			Ok(reply) => Ok(reply),
+			
+			Err(ReceiveError::Disconnected) => Err(RequestError::NoReply),
+			
+			Err(ReceiveError::Killed(kill_error)) => Err(RequestError::Killed(kill_error)),
+		}
+	}
+}
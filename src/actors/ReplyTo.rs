@@ -0,0 +1,26 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Used by an actor to reply to a request made with `ActorRef::request()`.
+#[derive(Debug)]
+pub struct ReplyTo<Reply: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(Sender<Reply, CoroutineHeapSize, GTACSA>);
+
+impl<Reply: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> ReplyTo<Reply, CoroutineHeapSize, GTACSA>
+{
+	/// Replies, waking the requester.
+	///
+	/// Returns the reply if the requester has given up waiting (eg because it was killed).
+	#[inline(always)]
+	pub fn reply(self, reply: Reply) -> Result<(), Reply>
+	{
+		match self.0.try_send(reply)
+		{
+			Ok(()) => Ok(()),
+			
+			Err(TrySendError::Disconnected(reply)) => Err(reply),
+			
+			Err(TrySendError::Full(_)) => unsafe { unreachable() },
+		}
+	}
+}
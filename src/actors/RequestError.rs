@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An error making a request of an actor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestError<E>
+{
+	/// The actor has stopped.
+	Dead,
+	
+	/// The actor dropped the `ReplyTo` without replying.
+	NoReply,
+	
+	/// The requesting coroutine was killed whilst waiting for a reply.
+	Killed(E),
+}
@@ -0,0 +1,24 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Starts an actor; execution will transfer to the actor's coroutine, which runs until it waits for its first message.
+///
+/// Returns `Err(AllocError)` if there is not memory available to start the actor.
+///
+/// If the actor panicked, this panics.
+#[inline(always)]
+pub fn spawn_actor<A: Actor<CoroutineHeapSize, GTACSA>, CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<ActorCoroutine<A, CoroutineHeapSize, GTACSA>, CoroutineInformation>>(coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, ActorCoroutine<A, CoroutineHeapSize, GTACSA>, CoroutineInformation, LifecycleHooks>, run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, coroutine_information: CoroutineInformation, start_arguments: A::StartArguments) -> Result<(ActorRef<A::Message, CoroutineHeapSize, GTACSA>, StartOutcome<(), A::Complete>), AllocError>
+{
+	let (sender, receiver) = unbounded_channel(run_queue);
+	
+	let (coroutine_instance_handle, start_outcome) = coroutine_manager.start_coroutine_returning_handle(coroutine_information, (start_arguments, Mailbox(receiver)))?;
+	
+	let actor_ref = ActorRef
+	{
+		coroutine_instance_handle,
+		run_queue: run_queue.clone(),
+		sender,
+	};
+	Ok((actor_ref, start_outcome))
+}
@@ -0,0 +1,107 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A connection to an `AdminSocket`, which reads a one line request then writes a one line response.
+#[derive(Debug)]
+struct AdminConnection
+{
+	stream: UnixStream,
+	request: Vec<u8>,
+	response: Vec<u8>,
+	written: usize,
+}
+
+impl AdminConnection
+{
+	const MaximumRequestLength: usize = 256;
+	
+	#[inline(always)]
+	fn new(stream: UnixStream) -> Self
+	{
+		Self
+		{
+			stream,
+			request: Vec::new(),
+			response: Vec::new(),
+			written: 0,
+		}
+	}
+	
+	/// Returns `Ok(true)` once the response has been written.
+	#[inline(always)]
+	fn progress(&mut self, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> io::Result<bool>
+	{
+		if self.response.is_empty()
+		{
+			match self.read_request()?
+			{
+				None => return Ok(false),
+				
+				Some(request) => self.response = AdminSocket::respond(&request, coroutine_managers),
+			}
+		}
+		
+		self.write_response()
+	}
+	
+	/// Returns `Ok(None)` if the request is incomplete.
+	#[inline(always)]
+	fn read_request(&mut self) -> io::Result<Option<String>>
+	{
+		let mut buffer = [0; 64];
+		loop
+		{
+			if let Some(end) = self.request.iter().position(|&byte| byte == b'\n')
+			{
+				return Ok(Some(String::from_utf8_lossy(&self.request[.. end]).into_owned()))
+			}
+			
+			if unlikely!(self.request.len() > Self::MaximumRequestLength)
+			{
+				return Err(io::Error::new(ErrorKind::InvalidData, "request is too long"))
+			}
+			
+			match self.stream.read(&mut buffer)
+			{
+				Ok(0) => return Ok(Some(String::from_utf8_lossy(&self.request).into_owned())),
+				
+				Ok(count) => self.request.extend_from_slice(&buffer[.. count]),
+				
+				Err(error) => match error.kind()
+				{
+					ErrorKind::WouldBlock => return Ok(None),
+					
+					ErrorKind::Interrupted => continue,
+					
+					_ => return Err(error),
+				}
+			}
+		}
+	}
+	
+	/// Returns `Ok(false)` if the response has not been completely written.
+	#[inline(always)]
+	fn write_response(&mut self) -> io::Result<bool>
+	{
+		while self.written < self.response.len()
+		{
+			match self.stream.write(&self.response[self.written .. ])
+			{
+				Ok(0) => return Err(io::Error::from(ErrorKind::WriteZero)),
+				
+				Ok(count) => self.written += count,
+				
+				Err(error) => match error.kind()
+				{
+					ErrorKind::WouldBlock => return Ok(false),
+					
+					ErrorKind::Interrupted => continue,
+					
+					_ => return Err(error),
+				}
+			}
+		}
+		Ok(true)
+	}
+}
@@ -0,0 +1,321 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A Unix domain socket to inspect the coroutines of a thread without attaching a debugger, eg using `echo stats | socat - UNIX-CONNECT:/path/to/socket`.
+///
+/// Served by the thread running the coroutine managers, in between starting and resuming coroutines: register `as_raw_fd()` with the thread's epoll or io_uring and call `poll()` when it is readable, and also call `poll()` periodically (eg from a timer) to progress connections that are part way through.
+///
+/// Connections are deliberately not served by coroutines.
+/// Answering a request borrows the coroutine managers inspected, but a coroutine can not hold such a borrow of its own coroutine manager, which is mutably borrowed whilst resuming it; it would also list and backtrace itself as running.
+/// `poll()` never blocks and serves at most 16 connections, so it can not stall the thread.
+///
+/// Each connection sends one request, a line, and receives one response, a line of JSON, then is closed.
+/// Requests are:-
+///
+/// * `stats`: the metrics of each coroutine manager;
+/// * `coroutines`: each live coroutine and its `CoroutineInformation`;
+/// * `backtrace <coroutine_instance_handle>`: the backtrace of a suspended coroutine;
+/// * `profile`: the stacks of suspended coroutines, in folded stack format;
+/// * `flight-recorder`: the events of this thread's flight recorder.
+///
+/// Errors are returned as `{"error":"..."}`.
+/// The socket file is removed when dropped.
+#[derive(Debug)]
+pub struct AdminSocket
+{
+	listener: UnixListener,
+	path: PathBuf,
+	connections: Vec<AdminConnection>,
+}
+
+impl Drop for AdminSocket
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let _ = remove_file(&self.path);
+	}
+}
+
+impl AsRawFd for AdminSocket
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		self.listener.as_raw_fd()
+	}
+}
+
+impl AdminSocket
+{
+	const MaximumConnections: usize = 16;
+	
+	/// Binds a new socket at `path`, which must not exist.
+	///
+	/// Only the owner can connect: the socket file's permissions are `0o600`.
+	/// It is bound inside a private (`0o700`) directory created next to `path` and then linked to `path`, so it is never accessible to others, not even briefly.
+	#[inline(always)]
+	pub fn bind(path: impl AsRef<Path>) -> io::Result<Self>
+	{
+		let path = path.as_ref();
+		
+		let private_directory =
+		{
+			let mut private_directory = path.as_os_str().to_owned();
+			private_directory.push(format!(".{}", process::id()));
+			PathBuf::from(private_directory)
+		};
+		DirBuilder::new().mode(0o700).create(&private_directory)?;
+		let listener = Self::bind_privately(path, &private_directory);
+		let _ = remove_dir(&private_directory);
+		let listener = listener?;
+		
+		if let Err(error) = listener.set_nonblocking(true)
+		{
+			let _ = remove_file(path);
+			return Err(error)
+		}
+		
+		Ok
+		(
+			Self
+			{
+				listener,
+				path: path.to_path_buf(),
+				connections: Vec::new(),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn bind_privately(path: &Path, private_directory: &Path) -> io::Result<UnixListener>
+	{
+		let private_path = private_directory.join("socket");
+		let listener = UnixListener::bind(&private_path)?;
+		let linked = set_permissions(&private_path, Permissions::from_mode(0o600)).and_then(|()| hard_link(&private_path, path));
+		let _ = remove_file(&private_path);
+		linked.map(|()| listener)
+	}
+	
+	/// Accepts connections and progresses them, answering requests by inspecting `coroutine_managers`.
+	///
+	/// Never blocks.
+	/// Connections in excess of 16, and those that fail, are closed.
+	pub fn poll(&mut self, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> io::Result<()>
+	{
+		loop
+		{
+			match self.listener.accept()
+			{
+				Ok((stream, _)) => if self.connections.len() < Self::MaximumConnections && stream.set_nonblocking(true).is_ok()
+				{
+					self.connections.push(AdminConnection::new(stream))
+				},
+				
+				Err(error) => match error.kind()
+				{
+					ErrorKind::WouldBlock => break,
+					
+					ErrorKind::Interrupted | ErrorKind::ConnectionAborted => continue,
+					
+					_ => return Err(error),
+				}
+			}
+		}
+		
+		let mut index = 0;
+		while index < self.connections.len()
+		{
+			match self.connections[index].progress(coroutine_managers)
+			{
+				Ok(false) => index += 1,
+				
+				Ok(true) | Err(_) =>
+				{
+					self.connections.swap_remove(index);
+				}
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn respond(request: &str, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> Vec<u8>
+	{
+		let mut response = Vec::new();
+		
+		let mut words = request.split_whitespace();
+		let written = match (words.next(), words.next(), words.next())
+		{
+			(Some("stats"), None, None) => Self::stats(&mut response, coroutine_managers),
+			
+			(Some("coroutines"), None, None) => Self::coroutines(&mut response, coroutine_managers),
+			
+			(Some("backtrace"), Some(coroutine_instance_handle), None) => match coroutine_instance_handle.parse::<u64>()
+			{
+				Ok(coroutine_instance_handle) => Self::backtrace(&mut response, coroutine_managers, CoroutineInstanceHandle::wrap(coroutine_instance_handle)),
+				
+				Err(_) => Self::error(&mut response, "coroutine instance handle is not a number"),
+			},
+			
+			(Some("profile"), None, None) => Self::profile(&mut response, coroutine_managers),
+			
+			(Some("flight-recorder"), None, None) => Self::flight_recorder(&mut response),
+			
+			_ => Self::error(&mut response, "unknown request"),
+		};
+		written.expect("Writing to a Vec<u8> does not fail");
+		
+		response.push(b'\n');
+		response
+	}
+	
+	fn stats(response: &mut Vec<u8>, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> io::Result<()>
+	{
+		response.push(b'[');
+		for (index, coroutine_manager) in coroutine_managers.iter().enumerate()
+		{
+			if index != 0
+			{
+				response.push(b',');
+			}
+			
+			let CoroutineManagerMetricsSnapshot { coroutine_manager_index, capacity, metrics } = coroutine_manager.introspected_metrics_snapshot();
+			write!(response, "{{\"coroutine_manager_index\":{},\"capacity\":{},\"started\":{},\"completed\":{},\"panicked\":{},\"cancelled\":{},\"live\":{},\"peak_live\":{},\"allocation_failures\":{},\"resumes\":{},\"on_cpu_cycles\":{}}}", coroutine_manager_index.0, capacity, metrics.started, metrics.completed, metrics.panicked, metrics.cancelled, metrics.live, metrics.peak_live, metrics.allocation_failures, metrics.resumes, metrics.on_cpu_cycles)?;
+		}
+		response.push(b']');
+		Ok(())
+	}
+	
+	fn coroutines(response: &mut Vec<u8>, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> io::Result<()>
+	{
+		response.push(b'[');
+		let mut first = true;
+		for coroutine_manager in coroutine_managers.iter()
+		{
+			let coroutine_manager_index = coroutine_manager.introspected_coroutine_manager_index();
+			for LiveCoroutine { coroutine_instance_handle, state, coroutine_information } in coroutine_manager.introspected_live_coroutines()
+			{
+				if first
+				{
+					first = false
+				}
+				else
+				{
+					response.push(b',');
+				}
+				
+				write!(response, "{{\"coroutine_manager_index\":{},\"coroutine_instance_handle\":{},\"state\":\"{:?}\",\"coroutine_information\":", coroutine_manager_index.0, coroutine_instance_handle.unwrap(), state)?;
+				write_json_string(response, &coroutine_information)?;
+				response.push(b'}');
+			}
+		}
+		response.push(b']');
+		Ok(())
+	}
+	
+	fn backtrace(response: &mut Vec<u8>, coroutine_managers: &[&dyn IntrospectableCoroutineManager], coroutine_instance_handle: CoroutineInstanceHandle) -> io::Result<()>
+	{
+		let backtrace = coroutine_managers.iter().filter(|coroutine_manager| coroutine_manager.introspected_coroutine_manager_index() == coroutine_instance_handle.coroutine_manager_index()).find_map(|coroutine_manager| coroutine_manager.introspected_backtrace(coroutine_instance_handle));
+		let backtrace = match backtrace
+		{
+			None => return Self::error(response, "coroutine is not live or not suspended"),
+			
+			Some(backtrace) => backtrace,
+		};
+		
+		write!(response, "{{\"coroutine_instance_handle\":{},\"frames\":[", coroutine_instance_handle.unwrap())?;
+		for (frame_index, frame) in backtrace.frames.iter().enumerate()
+		{
+			if frame_index != 0
+			{
+				response.push(b',');
+			}
+			
+			write!(response, "{{\"instruction_pointer\":{},\"symbols\":[", frame.instruction_pointer)?;
+			for (symbol_index, symbol) in frame.symbols.iter().enumerate()
+			{
+				if symbol_index != 0
+				{
+					response.push(b',');
+				}
+				
+				response.extend_from_slice(b"{\"name\":");
+				match symbol.name
+				{
+					None => response.extend_from_slice(b"null"),
+					
+					Some(ref name) => write_json_string(response, name)?,
+				}
+				
+				response.extend_from_slice(b",\"file\":");
+				match symbol.file
+				{
+					None => response.extend_from_slice(b"null"),
+					
+					Some(ref file) => write_json_string(response, &file.to_string_lossy())?,
+				}
+				
+				match symbol.line
+				{
+					None => response.extend_from_slice(b",\"line\":null}"),
+					
+					Some(line) => write!(response, ",\"line\":{}}}", line)?,
+				}
+			}
+			response.extend_from_slice(b"]}");
+		}
+		response.extend_from_slice(b"]}");
+		Ok(())
+	}
+	
+	fn profile(response: &mut Vec<u8>, coroutine_managers: &[&dyn IntrospectableCoroutineManager]) -> io::Result<()>
+	{
+		let mut parked_stack_profile = ParkedStackProfile::default();
+		for coroutine_manager in coroutine_managers.iter()
+		{
+			coroutine_manager.introspected_parked_stacks(&mut parked_stack_profile)
+		}
+		
+		let mut folded = Vec::new();
+		parked_stack_profile.write_folded(&mut folded)?;
+		
+		response.extend_from_slice(b"{\"folded\":");
+		write_json_string(response, &String::from_utf8_lossy(&folded))?;
+		response.push(b'}');
+		Ok(())
+	}
+	
+	fn flight_recorder(response: &mut Vec<u8>) -> io::Result<()>
+	{
+		let events = match flight_recorder_events()
+		{
+			None => return Self::error(response, "this thread does not have a flight recorder"),
+			
+			Some(events) => events,
+		};
+		
+		response.push(b'[');
+		for (index, event) in events.iter().enumerate()
+		{
+			if index != 0
+			{
+				response.push(b',');
+			}
+			
+			write!(response, "{{\"time_stamp_counter\":{},\"coroutine_instance_handle\":{},\"kind\":\"{}\"}}", event.time_stamp_counter, event.coroutine_instance_handle.unwrap(), event.kind.name())?;
+		}
+		response.push(b']');
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn error(response: &mut Vec<u8>, message: &str) -> io::Result<()>
+	{
+		response.extend_from_slice(b"{\"error\":");
+		write_json_string(response, message)?;
+		response.push(b'}');
+		Ok(())
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A coroutine manager that an `AdminSocket` can inspect.
+///
+/// Implemented for `CoroutineManager` if its `CoroutineInformation` implements `Debug`; object safe, so coroutine managers of different types of coroutine can be inspected together.
+pub trait IntrospectableCoroutineManager
+{
+	/// Index.
+	fn introspected_coroutine_manager_index(&self) -> CoroutineManagerIndex;
+	
+	/// See `CoroutineManager::metrics_snapshot()`.
+	fn introspected_metrics_snapshot(&self) -> CoroutineManagerMetricsSnapshot;
+	
+	/// See `CoroutineManager::iter_live()`.
+	fn introspected_live_coroutines(&self) -> Vec<LiveCoroutine>;
+	
+	/// See `CoroutineManager::backtrace()`.
+	///
+	/// `coroutine_instance_handle` can be untrusted (eg supplied by an operator), so it is only used if it is for a live coroutine instance of this coroutine manager.
+	fn introspected_backtrace(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> Option<CoroutineBacktrace>;
+	
+	/// See `CoroutineManager::profile_parked_stacks()`.
+	fn introspected_parked_stacks(&self, parked_stack_profile: &mut ParkedStackProfile);
+}
+
+impl<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, C: Coroutine, CoroutineInformation: 'static + Debug, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>> IntrospectableCoroutineManager for CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>
+{
+	#[inline(always)]
+	fn introspected_coroutine_manager_index(&self) -> CoroutineManagerIndex
+	{
+		self.index
+	}
+	
+	#[inline(always)]
+	fn introspected_metrics_snapshot(&self) -> CoroutineManagerMetricsSnapshot
+	{
+		self.metrics_snapshot()
+	}
+	
+	#[inline(always)]
+	fn introspected_live_coroutines(&self) -> Vec<LiveCoroutine>
+	{
+		self.iter_live().map(|(coroutine_instance_handle, coroutine_information, state)| LiveCoroutine { coroutine_instance_handle, state, coroutine_information: format!("{:?}", coroutine_information) }).collect()
+	}
+	
+	#[inline(always)]
+	fn introspected_backtrace(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> Option<CoroutineBacktrace>
+	{
+		self.backtrace(coroutine_instance_handle)
+	}
+	
+	#[inline(always)]
+	fn introspected_parked_stacks(&self, parked_stack_profile: &mut ParkedStackProfile)
+	{
+		self.profile_parked_stacks(parked_stack_profile)
+	}
+}
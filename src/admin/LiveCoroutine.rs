@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A live coroutine instance, as listed by an `AdminSocket`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LiveCoroutine
+{
+	/// Handle.
+	pub coroutine_instance_handle: CoroutineInstanceHandle,
+	
+	/// State.
+	pub state: CoroutineInstanceState,
+	
+	/// `Debug` rendering of the coroutine's `CoroutineInformation`.
+	pub coroutine_information: String,
+}
@@ -0,0 +1,38 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+use super::flight_recorder::*;
+use std::fs::DirBuilder;
+use std::fs::Permissions;
+use std::fs::hard_link;
+use std::fs::remove_dir;
+use std::fs::remove_file;
+use std::fs::set_permissions;
+use std::io;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::os::unix::fs::DirBuilderExt;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::io::RawFd;
+use std::os::unix::net::UnixListener;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+
+include!("write_json_string.rs");
+
+
+include!("AdminConnection.rs");
+include!("AdminSocket.rs");
+include!("IntrospectableCoroutineManager.rs");
+include!("LiveCoroutine.rs");
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn json_string(string: &str) -> String
+{
+	let mut response = Vec::new();
+	write_json_string(&mut response, string).unwrap();
+	String::from_utf8(response).unwrap()
+}
+
+#[test]
+fn write_json_string_quotes_plain_text()
+{
+	assert_eq!(json_string(""), "\"\"");
+	assert_eq!(json_string("Idle { id: 3 }"), "\"Idle { id: 3 }\"");
+}
+
+#[test]
+fn write_json_string_escapes_quotes_backslashes_and_control_characters()
+{
+	assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+	assert_eq!(json_string("\n\r\t"), "\"\\n\\r\\t\"");
+	assert_eq!(json_string("\u{0000}\u{001B}\u{001F}"), "\"\\u0000\\u001b\\u001f\"");
+}
+
+#[test]
+fn write_json_string_passes_through_non_ascii()
+{
+	assert_eq!(json_string("caf\u{00E9} \u{1F980}\u{007F}"), "\"caf\u{00E9} \u{1F980}\u{007F}\"");
+}
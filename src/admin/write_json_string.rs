@@ -0,0 +1,35 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Writes `string` as a JSON string, including quotes.
+#[inline(always)]
+fn write_json_string(response: &mut Vec<u8>, string: &str) -> io::Result<()>
+{
+	response.push(b'"');
+	for character in string.chars()
+	{
+		match character
+		{
+			'"' => response.extend_from_slice(b"\\\""),
+			
+			'\\' => response.extend_from_slice(b"\\\\"),
+			
+			'\n' => response.extend_from_slice(b"\\n"),
+			
+			'\r' => response.extend_from_slice(b"\\r"),
+			
+			'\t' => response.extend_from_slice(b"\\t"),
+			
+			'\u{0000}' ..= '\u{001F}' => write!(response, "\\u{:04x}", character as u32)?,
+			
+			_ =>
+			{
+				let mut buffer = [0; 4];
+				response.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes())
+			}
+		}
+	}
+	response.push(b'"');
+	Ok(())
+}
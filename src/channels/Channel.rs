@@ -0,0 +1,135 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+struct Channel<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	state: RefCell<ChannelState<T>>,
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Channel<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, capacity: Option<NonZeroUsize>) -> (Sender<T, CoroutineHeapSize, GTACSA>, Receiver<T, CoroutineHeapSize, GTACSA>)
+	{
+		let channel = run_queue.outside_coroutine_local_heap(||
+		{
+			let values = match capacity
+			{
+				None => VecDeque::new(),
+				
+				Some(capacity) => VecDeque::with_capacity(capacity.get()),
+			};
+			
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					state: RefCell::new
+					(
+						ChannelState
+						{
+							values,
+							capacity,
+							waiting_receivers: WaitQueue::default(),
+							waiting_senders: WaitQueue::default(),
+							number_of_senders: 1,
+							number_of_receivers: 1,
+						}
+					),
+				}
+			)
+		});
+		
+		(Sender::new(channel.clone()), Receiver::new(channel))
+	}
+	
+	#[inline(always)]
+	fn resource(&self) -> usize
+	{
+		self as *const Self as usize
+	}
+	
+	/// Held in the `WaitForGraph` by the coroutines holding a `Sender`.
+	#[inline(always)]
+	fn senders_resource(&self) -> usize
+	{
+		self.resource()
+	}
+	
+	/// Held in the `WaitForGraph` by the coroutines holding a `Receiver`.
+	///
+	/// Distinct from `senders_resource()` as a `Channel` is larger than one byte.
+	#[inline(always)]
+	fn receivers_resource(&self) -> usize
+	{
+		self.resource() + 1
+	}
+	
+	/// An endpoint is held by the last coroutine to use it.
+	///
+	/// An endpoint which has not yet been used is not held by any coroutine, as the coroutine which created it may move it to another coroutine.
+	#[inline(always)]
+	fn endpoint_used(&self, endpoint_resource: usize, holder: &Cell<Option<CoroutineInstanceHandle>>)
+	{
+		let current = current_coroutine_instance_handle();
+		if current.is_some() && current != holder.get()
+		{
+			self.run_queue.released(endpoint_resource, holder.replace(current));
+			self.run_queue.acquired(endpoint_resource, current)
+		}
+	}
+	
+	#[inline(always)]
+	fn endpoint_released(&self, endpoint_resource: usize, holder: &Cell<Option<CoroutineInstanceHandle>>)
+	{
+		self.run_queue.released(endpoint_resource, holder.get())
+	}
+	
+	#[inline(always)]
+	fn try_send(&self, value: T) -> Result<(), TrySendError<T>>
+	{
+		let mut state = self.state.borrow_mut();
+		
+		if unlikely!(state.number_of_receivers == 0)
+		{
+			return Err(TrySendError::Disconnected(value))
+		}
+		
+		if state.is_full()
+		{
+			return Err(TrySendError::Full(value))
+		}
+		
+		self.run_queue.outside_coroutine_local_heap(|| state.values.push_back(value));
+		state.waiting_receivers.wake_one(&self.run_queue);
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn try_receive(&self) -> Result<T, TryReceiveError>
+	{
+		let mut state = self.state.borrow_mut();
+		
+		match state.values.pop_front()
+		{
+			Some(value) =>
+			{
+				state.waiting_senders.wake_one(&self.run_queue);
+				Ok(value)
+			}
+			
+			None => if state.number_of_senders == 0
+			{
+				Err(TryReceiveError::Disconnected)
+			}
+			else
+			{
+				Err(TryReceiveError::Empty)
+			}
+		}
+	}
+}
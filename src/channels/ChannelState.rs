@@ -0,0 +1,30 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+struct ChannelState<T>
+{
+	values: VecDeque<T>,
+	capacity: Option<NonZeroUsize>,
+	
+	waiting_receivers: WaitQueue,
+	waiting_senders: WaitQueue,
+	
+	number_of_senders: usize,
+	number_of_receivers: usize,
+}
+
+impl<T> ChannelState<T>
+{
+	#[inline(always)]
+	fn is_full(&self) -> bool
+	{
+		match self.capacity
+		{
+			None => false,
+			
+			Some(capacity) => self.values.len() == capacity.get(),
+		}
+	}
+}
@@ -0,0 +1,78 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Receives values from a channel.
+///
+/// Clone to have multiple consumers.
+///
+/// For deadlock detection, a `Receiver` is held by the coroutine which created it until another coroutine receives with it.
+#[derive(Debug)]
+pub struct Receiver<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(Rc<Channel<T, CoroutineHeapSize, GTACSA>>, Cell<Option<CoroutineInstanceHandle>>);
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Clone for Receiver<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		self.0.state.borrow_mut().number_of_receivers += 1;
+		Self::new(self.0.clone())
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for Receiver<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.endpoint_released(self.0.receivers_resource(), &self.1);
+		
+		let mut state = self.0.state.borrow_mut();
+		state.number_of_receivers -= 1;
+		if state.number_of_receivers == 0
+		{
+			state.waiting_senders.wake_all(&self.0.run_queue)
+		}
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Receiver<T, CoroutineHeapSize, GTACSA>
+{
+	/// Receives a value, suspending the coroutine of `yielder` whilst the channel is empty.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn receive<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<T, ReceiveError<E>>
+	{
+		loop
+		{
+			match self.try_receive()
+			{
+				Ok(value) => return Ok(value),
+				
+				Err(TryReceiveError::Disconnected) => return Err(ReceiveError::Disconnected),
+				
+				Err(TryReceiveError::Empty) => (),
+			}
+			
+			if !yielder.wait(&self.0.run_queue, WaitingOn::ChannelReceive(self.0.resource()), &self.0.state, |state| &mut state.waiting_receivers, yields())
+			{
+				return Err(ReceiveError::Killed(kill_error))
+			}
+		}
+	}
+	
+	/// Receives a value without suspending.
+	#[inline(always)]
+	pub fn try_receive(&self) -> Result<T, TryReceiveError>
+	{
+		self.0.endpoint_used(self.0.receivers_resource(), &self.1);
+		self.0.try_receive()
+	}
+	
+	#[inline(always)]
+	fn new(channel: Rc<Channel<T, CoroutineHeapSize, GTACSA>>) -> Self
+	{
+		Self(channel, Cell::new(None))
+	}
+}
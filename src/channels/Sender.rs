@@ -0,0 +1,81 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Sends values to a channel.
+///
+/// Clone to have multiple producers.
+///
+/// For deadlock detection, a `Sender` is held by the coroutine which created it until another coroutine sends with it.
+#[derive(Debug)]
+pub struct Sender<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(Rc<Channel<T, CoroutineHeapSize, GTACSA>>, Cell<Option<CoroutineInstanceHandle>>);
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Clone for Sender<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		self.0.state.borrow_mut().number_of_senders += 1;
+		Self::new(self.0.clone())
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for Sender<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.endpoint_released(self.0.senders_resource(), &self.1);
+		
+		let mut state = self.0.state.borrow_mut();
+		state.number_of_senders -= 1;
+		if state.number_of_senders == 0
+		{
+			state.waiting_receivers.wake_all(&self.0.run_queue)
+		}
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Sender<T, CoroutineHeapSize, GTACSA>
+{
+	/// Sends a value, suspending the coroutine of `yielder` whilst the channel is full.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	///
+	/// Values which own memory should allocate it outside of the coroutine-local heap (eg using `RunQueue::outside_coroutine_local_heap()`) so that it remains valid if this coroutine completes before the value is received.
+	#[inline(always)]
+	pub fn send<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, value: T, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<(), SendError<T, E>>
+	{
+		let mut value = value;
+		loop
+		{
+			value = match self.try_send(value)
+			{
+				Ok(()) => return Ok(()),
+				
+				Err(TrySendError::Disconnected(value)) => return Err(SendError::Disconnected(value)),
+				
+				Err(TrySendError::Full(value)) => value,
+			};
+			
+			if !yielder.wait(&self.0.run_queue, WaitingOn::ChannelSend(self.0.resource()), &self.0.state, |state| &mut state.waiting_senders, yields())
+			{
+				return Err(SendError::Killed(value, kill_error))
+			}
+		}
+	}
+	
+	/// Sends a value without suspending.
+	#[inline(always)]
+	pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>>
+	{
+		self.0.endpoint_used(self.0.senders_resource(), &self.1);
+		self.0.try_send(value)
+	}
+	
+	#[inline(always)]
+	fn new(channel: Rc<Channel<T, CoroutineHeapSize, GTACSA>>) -> Self
+	{
+		Self(channel, Cell::new(None))
+	}
+}
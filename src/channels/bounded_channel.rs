@@ -0,0 +1,14 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Creates a channel that holds at most `capacity` values.
+///
+/// Sending to a full channel suspends the sending coroutine until a value is received.
+///
+/// All memory for values is allocated up-front using the thread-local allocator.
+#[inline(always)]
+pub fn bounded_channel<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, capacity: NonZeroUsize) -> (Sender<T, CoroutineHeapSize, GTACSA>, Receiver<T, CoroutineHeapSize, GTACSA>)
+{
+	Channel::new(run_queue, Some(capacity))
+}
@@ -39,4 +39,19 @@ impl SavedContextWrapper
 	{
 		unsafe { SavedContext::resume(self.0, data_to_transfer) }
 	}
+
+	/// Registers saved when this context was suspended by `resume()`.
+	///
+	/// Before the first `resume()` of a context created with `initialize()`, these are meaningless.
+	#[inline(always)]
+	fn suspended_registers(self) -> SuspendedRegisters
+	{
+		let saved_context = unsafe { self.0.as_ref() };
+		SuspendedRegisters
+		{
+			stack_pointer: self.0.as_ptr() as usize,
+			frame_pointer: saved_context.register_rbp_value as usize,
+			instruction_pointer: saved_context.resume_instruction_pointer as usize,
+		}
+	}
 }
@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Registers of a suspended context, read from its `SavedContext`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct SuspendedRegisters
+{
+	/// Address of the `SavedContext`, which is the lowest address of the suspended context's stack in use.
+	pub(crate) stack_pointer: usize,
+
+	/// Value of the register RBP.
+	pub(crate) frame_pointer: usize,
+
+	/// Location to resume execution at.
+	pub(crate) instruction_pointer: usize,
+}
@@ -44,6 +44,13 @@ impl Transfer
 		*self = self.previously_executed_context_which_yielded_to_resume_the_current_context.resume(data_to_transfer.into_usize())
 	}
 
+	/// Registers of the previously executed context, which is suspended.
+	#[inline(always)]
+	pub(crate) fn suspended_registers(&self) -> SuspendedRegisters
+	{
+		self.previously_executed_context_which_yielded_to_resume_the_current_context.suspended_registers()
+	}
+
 	/// Get data.
 	///
 	/// It is your responsibility to make sure `data_to_transfer` lives longer than the stack above this function call; use `TypeSafeTransfer` in preference to this code.
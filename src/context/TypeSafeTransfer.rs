@@ -72,6 +72,13 @@ impl<Receive: Sized, Send: Sized> TypeSafeTransfer<Receive, Send>
 		self.take_data()
 	}
 
+	/// Registers of the context this transfer resumes, which is suspended.
+	#[inline(always)]
+	pub(crate) fn suspended_registers(&self) -> SuspendedRegisters
+	{
+		self.transfer.suspended_registers()
+	}
+
 	#[inline(always)]
 	pub(crate) fn start_child_arguments<T>(&self) -> T
 	{
@@ -9,6 +9,7 @@ include!("ContextEntryPointFunctionPointer.rs");
 include!("DataToTransfer.rs");
 include!("SavedContext.rs");
 include!("SavedContextWrapper.rs");
+include!("SuspendedRegisters.rs");
 include!("Transfer.rs");
 include!("TransferableData.rs");
 include!("TypeSafeTransfer.rs");
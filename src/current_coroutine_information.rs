@@ -0,0 +1,21 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+thread_local!
+{
+	static CurrentCoroutineInformation: Cell<Option<(NonNull<()>, TypeId)>> = Cell::new(None);
+}
+
+/// Type-erased pointer to the `CoroutineInformation` of the coroutine currently running on this thread, if any, and the type it points to.
+#[inline(always)]
+fn current_coroutine_information() -> Option<(NonNull<()>, TypeId)>
+{
+	CurrentCoroutineInformation.with(|current_coroutine_information| current_coroutine_information.get())
+}
+
+#[inline(always)]
+fn replace_current_coroutine_information(coroutine_information: Option<(NonNull<()>, TypeId)>) -> Option<(NonNull<()>, TypeId)>
+{
+	CurrentCoroutineInformation.with(|current_coroutine_information| current_coroutine_information.replace(coroutine_information))
+}
@@ -0,0 +1,21 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+thread_local!
+{
+	static CurrentCoroutineInstanceHandle: Cell<Option<CoroutineInstanceHandle>> = Cell::new(None);
+}
+
+/// The handle of the coroutine currently running on this thread, if any.
+#[inline(always)]
+pub fn current_coroutine_instance_handle() -> Option<CoroutineInstanceHandle>
+{
+	CurrentCoroutineInstanceHandle.with(|current_coroutine_instance_handle| current_coroutine_instance_handle.get())
+}
+
+#[inline(always)]
+fn replace_current_coroutine_instance_handle(coroutine_instance_handle: Option<CoroutineInstanceHandle>) -> Option<CoroutineInstanceHandle>
+{
+	CurrentCoroutineInstanceHandle.with(|current_coroutine_instance_handle| current_coroutine_instance_handle.replace(coroutine_instance_handle))
+}
@@ -0,0 +1,46 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[thread_local]
+static CurrentFlightRecorder: Cell<*const FlightRecorder> = Cell::new(null());
+
+/// A ring buffer of the most recent events on a thread.
+///
+/// Only accessed by its thread, including from signal handlers on it, so an event being recorded when a dump interrupts may be partially written.
+#[derive(Debug)]
+struct FlightRecorder
+{
+	events: Box<[Cell<Option<FlightRecorderEvent>>]>,
+	recorded: Cell<usize>,
+}
+
+impl FlightRecorder
+{
+	#[inline(always)]
+	fn new(capacity: NonZeroUsize) -> Self
+	{
+		Self
+		{
+			events: (0 .. capacity.get()).map(|_| Cell::new(None)).collect(),
+			recorded: Cell::new(0),
+		}
+	}
+	
+	#[inline(always)]
+	fn record(&self, coroutine_instance_handle: CoroutineInstanceHandle, kind: FlightRecorderEventKind)
+	{
+		let recorded = self.recorded.get();
+		self.events[recorded % self.events.len()].set(Some(FlightRecorderEvent { coroutine_instance_handle, time_stamp_counter: time_stamp_counter(), kind }));
+		self.recorded.set(recorded.wrapping_add(1))
+	}
+	
+	/// Events, oldest first.
+	#[inline(always)]
+	fn events(&self) -> impl Iterator<Item=FlightRecorderEvent> + '_
+	{
+		let capacity = self.events.len();
+		let oldest = self.recorded.get() % capacity;
+		(0 .. capacity).filter_map(move |offset| self.events[(oldest + offset) % capacity].get())
+	}
+}
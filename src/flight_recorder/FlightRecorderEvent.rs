@@ -0,0 +1,26 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An event recorded by a flight recorder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FlightRecorderEvent
+{
+	/// Handle of the coroutine.
+	pub coroutine_instance_handle: CoroutineInstanceHandle,
+	
+	/// Value of the time stamp counter (`rdtsc`) when recorded.
+	pub time_stamp_counter: u64,
+	
+	/// Kind.
+	pub kind: FlightRecorderEventKind,
+}
+
+impl Display for FlightRecorderEvent
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "{:>20} {:#018x} {}", self.time_stamp_counter, self.coroutine_instance_handle.unwrap(), self.kind.name())
+	}
+}
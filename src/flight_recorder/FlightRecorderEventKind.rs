@@ -0,0 +1,52 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Kind of a `FlightRecorderEvent`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FlightRecorderEventKind
+{
+	/// Control was transferred to a coroutine for the first time.
+	Start,
+	
+	/// Control was transferred to a coroutine again.
+	Resume,
+	
+	/// A coroutine yielded (or exhausted its preemption budget).
+	Yield,
+	
+	/// A coroutine completed.
+	Complete,
+	
+	/// A suspended coroutine was killed, eg because it was cancelled.
+	Kill,
+	
+	/// A coroutine panicked.
+	Panic,
+}
+
+impl FlightRecorderEventKind
+{
+	/// Name.
+	#[inline(always)]
+	pub const fn name(self) -> &'static str
+	{
+		use self::FlightRecorderEventKind::*;
+		
+		match self
+		{
+			Start => "start",
+			
+			Resume => "resume",
+			
+			Yield => "yield",
+			
+			Complete => "complete",
+			
+			Kill => "kill",
+			
+			Panic => "panic",
+		}
+	}
+}
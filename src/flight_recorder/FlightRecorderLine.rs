@@ -0,0 +1,50 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A fixed-size line of text, so that events can be formatted without allocating memory.
+struct FlightRecorderLine
+{
+	bytes: [u8; Self::Capacity],
+	length: usize,
+}
+
+impl fmt::Write for FlightRecorderLine
+{
+	#[inline(always)]
+	fn write_str(&mut self, s: &str) -> fmt::Result
+	{
+		let bytes = s.as_bytes();
+		let end = self.length + bytes.len();
+		if unlikely!(end > Self::Capacity)
+		{
+			return Err(fmt::Error)
+		}
+		self.bytes[self.length .. end].copy_from_slice(bytes);
+		self.length = end;
+		Ok(())
+	}
+}
+
+impl FlightRecorderLine
+{
+	const Capacity: usize = 64;
+	
+	#[inline(always)]
+	fn format(event: FlightRecorderEvent) -> Self
+	{
+		let mut line = Self
+		{
+			bytes: [0; Self::Capacity],
+			length: 0,
+		};
+		let _ = fmt::Write::write_fmt(&mut line, format_args!("{}\n", event));
+		line
+	}
+	
+	#[inline(always)]
+	fn as_bytes(&self) -> &[u8]
+	{
+		&self.bytes[.. self.length]
+	}
+}
@@ -0,0 +1,61 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A flight recorder for the current thread, which records the most recent starts, resumes, yields, completions, kills and panics of coroutines; removed when dropped.
+///
+/// Use `dump_flight_recorder()` to write the events from a panic hook or signal handler.
+///
+/// Not `Send`: must be dropped on the thread that registered.
+#[derive(Debug)]
+pub struct FlightRecorderRegistration<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	global_allocator: &'static GTACSA,
+	flight_recorder: Option<Box<FlightRecorder>>,
+	marker: PhantomData<(CoroutineHeapSize, *const ())>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for FlightRecorderRegistration<CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		CurrentFlightRecorder.set(null());
+		
+		let _restore = RestoreCurrentAllocatorInUse::switch(self.global_allocator, CurrentAllocatorInUse::Global);
+		drop(self.flight_recorder.take())
+	}
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> FlightRecorderRegistration<CoroutineHeapSize, GTACSA>
+{
+	/// Gives the current thread a flight recorder of the most recent `capacity` events.
+	///
+	/// Only one flight recorder can be registered with a thread at a time; this panics if the current thread already has one.
+	#[inline(always)]
+	pub fn register_current_thread(global_allocator: &'static GTACSA, capacity: NonZeroUsize) -> Self
+	{
+		assert!(CurrentFlightRecorder.get().is_null(), "This thread already has a flight recorder");
+		
+		let flight_recorder =
+		{
+			let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+			Box::new(FlightRecorder::new(capacity))
+		};
+		CurrentFlightRecorder.set(&*flight_recorder);
+		
+		Self
+		{
+			global_allocator,
+			flight_recorder: Some(flight_recorder),
+			marker: PhantomData,
+		}
+	}
+	
+	/// Events, oldest first.
+	#[inline(always)]
+	pub fn events(&self) -> Vec<FlightRecorderEvent>
+	{
+		self.flight_recorder.as_ref().unwrap().events().collect()
+	}
+}
@@ -0,0 +1,22 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Writes the events of this thread's flight recorder, if it has one, oldest first, one per line, to `file_descriptor` (eg `2` for standard error).
+///
+/// Does not allocate memory or take locks, so can be called from a panic hook or a signal handler on this thread, eg to show the coroutines that last ran before a crash.
+pub fn dump_flight_recorder(file_descriptor: RawFd)
+{
+	let flight_recorder = CurrentFlightRecorder.get();
+	if flight_recorder.is_null()
+	{
+		return
+	}
+	
+	for event in unsafe { &*flight_recorder }.events()
+	{
+		let line = FlightRecorderLine::format(event);
+		let bytes = line.as_bytes();
+		unsafe { write(file_descriptor, bytes.as_ptr() as *const _, bytes.len()) };
+	}
+}
@@ -0,0 +1,18 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Events of this thread's flight recorder, oldest first, or `None` if it does not have one.
+#[inline(always)]
+pub fn flight_recorder_events() -> Option<Vec<FlightRecorderEvent>>
+{
+	let flight_recorder = CurrentFlightRecorder.get();
+	if flight_recorder.is_null()
+	{
+		None
+	}
+	else
+	{
+		Some(unsafe { &*flight_recorder }.events().collect())
+	}
+}
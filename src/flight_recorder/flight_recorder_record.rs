@@ -0,0 +1,15 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Records an event if this thread has a flight recorder.
+#[inline(always)]
+pub(crate) fn flight_recorder_record(coroutine_instance_handle: CoroutineInstanceHandle, kind: FlightRecorderEventKind)
+{
+	let flight_recorder = CurrentFlightRecorder.get();
+	if likely!(flight_recorder.is_null())
+	{
+		return
+	}
+	unsafe { &*flight_recorder }.record(coroutine_instance_handle, kind)
+}
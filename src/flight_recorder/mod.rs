@@ -0,0 +1,24 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+use libc::write;
+use std::os::unix::io::RawFd;
+use std::ptr::null;
+
+
+include!("dump_flight_recorder.rs");
+include!("flight_recorder_events.rs");
+include!("flight_recorder_record.rs");
+
+
+include!("FlightRecorder.rs");
+include!("FlightRecorderEvent.rs");
+include!("FlightRecorderEventKind.rs");
+include!("FlightRecorderLine.rs");
+include!("FlightRecorderRegistration.rs");
+
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn handles(flight_recorder: &FlightRecorder) -> Vec<u64>
+{
+	flight_recorder.events().map(|event| event.coroutine_instance_handle.unwrap()).collect()
+}
+
+#[test]
+fn events_are_oldest_first_before_wrapping()
+{
+	let flight_recorder = FlightRecorder::new(NonZeroUsize::new(4).unwrap());
+	assert_eq!(handles(&flight_recorder), Vec::<u64>::new());
+	
+	flight_recorder.record(CoroutineInstanceHandle::wrap(1), FlightRecorderEventKind::Start);
+	flight_recorder.record(CoroutineInstanceHandle::wrap(2), FlightRecorderEventKind::Yield);
+	
+	assert_eq!(handles(&flight_recorder), vec![1, 2]);
+}
+
+#[test]
+fn events_overwrite_oldest_once_full()
+{
+	let flight_recorder = FlightRecorder::new(NonZeroUsize::new(3).unwrap());
+	for handle in 1 ..= 7
+	{
+		flight_recorder.record(CoroutineInstanceHandle::wrap(handle), FlightRecorderEventKind::Resume);
+	}
+	
+	assert_eq!(handles(&flight_recorder), vec![5, 6, 7]);
+}
+
+#[test]
+fn events_are_oldest_first_when_exactly_full()
+{
+	let flight_recorder = FlightRecorder::new(NonZeroUsize::new(3).unwrap());
+	for handle in 1 ..= 3
+	{
+		flight_recorder.record(CoroutineInstanceHandle::wrap(handle), FlightRecorderEventKind::Complete);
+	}
+	
+	assert_eq!(handles(&flight_recorder), vec![1, 2, 3]);
+	assert!(flight_recorder.events().all(|event| event.kind == FlightRecorderEventKind::Complete));
+}
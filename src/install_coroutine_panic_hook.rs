@@ -0,0 +1,43 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+thread_local!
+{
+	static CurrentCoroutinePanicBacktrace: RefCell<Option<(CoroutineInstanceHandle, Backtrace)>> = RefCell::new(None);
+}
+
+/// Installs a panic hook that captures a `Backtrace` on the stack of a coroutine when it panics, for `CoroutinePanic.backtrace`.
+///
+/// The previous panic hook is still called.
+/// The backtrace is allocated using the global allocator, as the coroutine's heap is freed when it finishes.
+pub fn install_coroutine_panic_hook<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync>(global_allocator: &'static GTACSA)
+{
+	let previous_panic_hook = take_hook();
+	set_hook
+	(
+		Box::new(move |panic_info|
+		{
+			if let Some(coroutine_instance_handle) = current_coroutine_instance_handle()
+			{
+				let _restore_current_allocator_in_use = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+				let backtrace = Backtrace::force_capture();
+				CurrentCoroutinePanicBacktrace.with(|current_coroutine_panic_backtrace| *current_coroutine_panic_backtrace.borrow_mut() = Some((coroutine_instance_handle, backtrace)));
+			}
+			previous_panic_hook(panic_info)
+		})
+	)
+}
+
+/// Backtrace captured by the panic hook installed by `install_coroutine_panic_hook()`, if any, when the coroutine `coroutine_instance_handle` panicked.
+#[inline(always)]
+fn take_coroutine_panic_backtrace<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(global_allocator: &'static GTACSA, coroutine_instance_handle: CoroutineInstanceHandle) -> Backtrace
+{
+	let _restore_current_allocator_in_use = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+	match CurrentCoroutinePanicBacktrace.with(|current_coroutine_panic_backtrace| current_coroutine_panic_backtrace.borrow_mut().take())
+	{
+		Some((panicked_coroutine_instance_handle, backtrace)) if panicked_coroutine_instance_handle == coroutine_instance_handle => backtrace,
+		
+		_ => Backtrace::disabled(),
+	}
+}
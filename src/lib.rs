@@ -13,6 +13,7 @@
 #![feature(global_asm)]
 #![feature(llvm_asm)]
 #![feature(naked_functions)]
+#![feature(thread_local)]
 
 
 //! # context-coroutine
@@ -41,60 +42,188 @@ assert_cfg!(target_pointer_width = "64");
 
 
 use self::context::*;
+use self::flight_recorder::*;
+use self::preemption::*;
+use self::scopes::*;
 use self::stacks::*;
+use self::timers::*;
+use self::watchdog::*;
+use backtrace::resolve;
 use const_fn_assert::cfn_debug_assert;
 use context_allocator::*;
 use context_allocator::memory_sources::*;
+use libc::SYS_io_uring_register;
+use libc::syscall;
 use likely::*;
 use linux_support::memory::huge_pages::DefaultHugePageSizes;
 use linux_support::memory::mapping::MappedMemory;
 use magic_ring_buffer::*;
 use magic_ring_buffer::memory_sizes::MemorySize;
 use std::alloc::AllocError;
+use std::any::Any;
+use std::any::TypeId;
+use std::arch::x86_64::_rdtsc;
+use std::backtrace::Backtrace;
+use std::backtrace::BacktraceStatus;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::convert::TryFrom;
+use std::error;
+use std::ffi::c_void;
 use std::fmt;
 use std::fmt::Debug;
+use std::fmt::Display;
 use std::fmt::Formatter;
 use std::intrinsics::unreachable;
+use std::io;
+use std::io::Write;
 use std::marker::PhantomData;
+use std::mem::forget;
 use std::mem::size_of;
 use std::num::NonZeroU64;
 use std::num::NonZeroUsize;
+use std::os::unix::io::RawFd;
 use std::panic::*;
+use std::path::Path;
+use std::path::PathBuf;
 use std::ptr::NonNull;
+use std::ptr::addr_of;
+use std::ptr::null;
 use std::ptr::read;
 use std::ptr::write;
+use std::rc::Rc;
 use std::thread;
+use std::time::Duration;
+use std::time::Instant;
 use swiss_army_knife::non_zero::new_non_null;
 use swiss_army_knife::non_zero::new_non_zero_u64;
+use swiss_army_knife::non_zero::new_non_zero_usize;
 use swiss_army_knife::unsafe_initialization::unsafe_uninitialized;
+#[cfg(feature = "usdt")] use probe::probe_lazy;
+#[cfg(feature = "tracing")] use tracing::Span;
+#[cfg(feature = "tracing")] use tracing::dispatcher;
+#[cfg(feature = "tracing")] use tracing::info_span;
+#[cfg(feature = "tracing")] use tracing::span::EnteredSpan;
+#[cfg(feature = "tracing")] use tracing::span::Id;
 
 
 include!("choose_coroutine_manager.rs");
+include!("current_coroutine_information.rs");
+include!("current_coroutine_instance_handle.rs");
+include!("install_coroutine_panic_hook.rs");
+include!("remaining_preemption_budget.rs");
+include!("usdt_probe.rs");
 
 
 include!("ChildOutcome.rs");
 include!("Coroutine.rs");
+include!("CoroutineBacktrace.rs");
+include!("CoroutineBacktraceFrame.rs");
+include!("CoroutineBacktraceSymbol.rs");
 include!("CoroutineGenerationCounter.rs");
 include!("CoroutineInstance.rs");
 include!("CoroutineInstanceAllocator.rs");
 include!("CoroutineInstanceHandle.rs");
+include!("CoroutineInstanceMetrics.rs");
 include!("CoroutineInstancePointer.rs");
+include!("CoroutineInstanceState.rs");
+include!("CoroutineLifecycleHooks.rs");
 include!("CoroutineManager.rs");
 include!("CoroutineManagerIndex.rs");
+include!("CoroutineManagerMetrics.rs");
+include!("CoroutineManagerMetricsSnapshot.rs");
+include!("CoroutinePanic.rs");
+include!("CoroutinePanicDescriber.rs");
+#[cfg(feature = "tracing")] include!("CoroutineSpanFactory.rs");
+include!("DeadlineOutcome.rs");
+include!("Deadlock.rs");
+include!("IoUringFixedBuffer.rs");
+include!("IoUringFixedBuffers.rs");
+include!("IoUringFixedBuffersError.rs");
+include!("IoUringFixedBuffersRegistration.rs");
+include!("JoinHandle.rs");
+include!("JoinOutcome.rs");
+include!("JoinSlot.rs");
+include!("JoinState.rs");
+include!("NoCoroutineLifecycleHooks.rs");
 include!("ParentInstructingChild.rs");
+include!("ParkedStackProfile.rs");
+include!("PreemptionBudget.rs");
+include!("RemainingPreemptionBudget.rs");
+include!("RestoreCurrentAllocatorInUse.rs");
 include!("ResumeOutcome.rs");
+include!("RunQueue.rs");
 include!("StartOutcome.rs");
 include!("TaggedRelativePointerToData.rs");
 include!("UserBits.rs");
+include!("WaitForGraph.rs");
+include!("WaitQueue.rs");
+include!("WaitingOn.rs");
 include!("Yielder.rs");
 
 
+/// Actors: coroutines with typed mailboxes.
+pub mod actors;
+
+
+/// A local Unix domain socket to inspect coroutines in a running process.
+pub mod admin;
+
+
+/// Channels to pass values between coroutines on one thread.
+pub mod channels;
+
+
 /// Context; derived from `Boost.Context` and [context-rs](https://github.com/zonyitoo/context-rs).
 ///
 /// Use the `TypeSafeTransfer` struct to work with contexts (or the lower-level `Transfer`).
 pub mod context;
 
 
+/// A per-thread flight recorder of the most recent coroutine context switches, for post-mortem debugging.
+pub mod flight_recorder;
+
+
+/// Offloading of blocking or CPU-heavy work from coroutines to a pool of threads.
+pub mod offload;
+
+
+/// Opt-in, signal-driven, preemption of coroutines that run for too long without yielding.
+pub mod preemption;
+
+
+/// Wake ups of coroutines from other threads.
+pub mod remote;
+
+
+/// Structured concurrency scopes (nurseries) of child coroutines.
+pub mod scopes;
+
+
 /// Stack implementations.
 pub mod stacks;
+
+
+/// Erlang-style supervision of coroutines, restarting them when they fail.
+pub mod supervision;
+
+
+/// Synchronization primitives for coroutines on one thread that suspend the coroutine rather than blocking the thread.
+pub mod synchronization;
+
+
+/// Timers to resume coroutines after a delay or to cancel those that run for too long.
+pub mod timers;
+
+
+/// A diagnostic watchdog which reports coroutines that have not yielded for too long.
+pub mod watchdog;
+
+
+#[cfg(test)]
+mod tests;
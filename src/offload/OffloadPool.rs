@@ -0,0 +1,68 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A pool of threads to execute blocking system calls or CPU-heavy functions on behalf of coroutines, so that other coroutines on the coroutine's thread are not stalled.
+///
+/// Shared by many coroutine-owning threads; each should create its own `Offloader`.
+///
+/// Dropping the pool waits for all submitted work to finish.
+#[derive(Debug)]
+pub struct OffloadPool
+{
+	shared: Arc<OffloadPoolShared>,
+	threads: Vec<thread::JoinHandle<()>>,
+}
+
+impl Drop for OffloadPool
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.shared.shutdown();
+		for thread in self.threads.drain(..)
+		{
+			let _ = thread.join();
+		}
+	}
+}
+
+impl OffloadPool
+{
+	/// Creates a new pool of `number_of_threads` threads.
+	///
+	/// Should be created outside of a coroutine.
+	#[inline(always)]
+	pub fn new(number_of_threads: NonZeroUsize) -> io::Result<Self>
+	{
+		let shared = Arc::new(OffloadPoolShared::default());
+		let mut this = Self
+		{
+			shared,
+			threads: Vec::with_capacity(number_of_threads.get()),
+		};
+		
+		for thread_index in 0 .. number_of_threads.get()
+		{
+			let shared = this.shared.clone();
+			let thread = thread::Builder::new().name(format!("offload-{}", thread_index)).spawn(move || shared.run())?;
+			this.threads.push(thread);
+		}
+		
+		Ok(this)
+	}
+	
+	/// Creates an offloader for coroutines on this thread.
+	///
+	/// Coroutines whose offloaded work has finished are woken by `remote_wake_ups`; the owner of `remote_wake_ups` should call `RemoteWakeUps::wake_on_run_queue()` when its eventfd is readable.
+	#[inline(always)]
+	pub fn offloader<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync>(&self, global_allocator: &'static GTACSA, remote_wake_ups: &RemoteWakeUps<(), CoroutineHeapSize, GTACSA>) -> Offloader<CoroutineHeapSize, GTACSA>
+	{
+		Offloader
+		{
+			global_allocator,
+			shared: self.shared.clone(),
+			remote_waker: remote_wake_ups.remote_waker(),
+		}
+	}
+}
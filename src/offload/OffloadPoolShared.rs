@@ -0,0 +1,63 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Shared between an `OffloadPool`, its threads and every `Offloader`.
+#[derive(Default)]
+struct OffloadPoolShared
+{
+	queue: Mutex<OffloadQueue>,
+	job_available: Condvar,
+}
+
+impl Debug for OffloadPoolShared
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "OffloadPoolShared")
+	}
+}
+
+impl OffloadPoolShared
+{
+	#[inline(always)]
+	fn submit(&self, offload_job: OffloadJob)
+	{
+		self.queue.lock().unwrap().jobs.push_back(offload_job);
+		self.job_available.notify_one()
+	}
+	
+	#[inline(always)]
+	fn shutdown(&self)
+	{
+		self.queue.lock().unwrap().shutting_down = true;
+		self.job_available.notify_all()
+	}
+	
+	/// Run by each thread of the pool; jobs still queued when shutting down are executed before the thread exits.
+	#[inline(always)]
+	fn run(&self)
+	{
+		loop
+		{
+			let offload_job =
+			{
+				let mut queue = self.queue.lock().unwrap();
+				loop
+				{
+					if let Some(offload_job) = queue.jobs.pop_front()
+					{
+						break offload_job
+					}
+					if queue.shutting_down
+					{
+						return
+					}
+					queue = self.job_available.wait(queue).unwrap();
+				}
+			};
+			offload_job()
+		}
+	}
+}
@@ -0,0 +1,82 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Used by coroutines on one thread to offload work to an `OffloadPool`.
+#[derive(Debug)]
+pub struct Offloader<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync>
+{
+	global_allocator: &'static GTACSA,
+	shared: Arc<OffloadPoolShared>,
+	remote_waker: RemoteWaker<(), CoroutineHeapSize, GTACSA>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync> Offloader<CoroutineHeapSize, GTACSA>
+{
+	/// Executes `work` on a thread of the pool, suspending the coroutine of `yielder` until it has finished; returns the result of `work`.
+	///
+	/// `work` (including its captures) and its result are allocated using the global allocator rather than the coroutine-local allocator.
+	/// However, any memory owned by values captured by `work` must also not have been allocated from the coroutine-local heap, eg by using `RunQueue::outside_coroutine_local_heap()`, as it will be freed on another thread.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	///
+	/// Returns `Err(kill_error)` if killed before `work` has finished; `work` continues to run and its result is dropped.
+	///
+	/// If `work` panics, the panic is resumed in the coroutine.
+	#[inline(always)]
+	pub fn offload<R: 'static + Send, ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, work: impl 'static + FnOnce() -> R + Send, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<R, E>
+	{
+		let global_allocator = self.global_allocator;
+		let coroutine_instance_handle = yielder.coroutine_instance_handle();
+		
+		let result_slot =
+		{
+			let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+			
+			let result_slot = Arc::new(Mutex::new(None));
+			
+			let result = result_slot.clone();
+			let remote_waker = self.remote_waker.clone();
+			let offload_job: OffloadJob = Box::new(move ||
+			{
+				let thread_result =
+				{
+					let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+					catch_unwind(AssertUnwindSafe(work))
+				};
+				*result.lock().unwrap() = Some(thread_result);
+				remote_waker.wake(coroutine_instance_handle, ())
+			});
+			self.shared.submit(offload_job);
+			
+			result_slot
+		};
+		
+		let outcome = loop
+		{
+			if let Some(thread_result) = result_slot.lock().unwrap().take()
+			{
+				break Ok(thread_result)
+			}
+			
+			if yielder.suspend(yields()).is_none()
+			{
+				break Err(kill_error)
+			}
+		};
+		
+		{
+			let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+			drop(result_slot);
+		}
+		
+		match outcome
+		{
+			Ok(Ok(result)) => Ok(result),
+			
+			Ok(Err(panic_payload)) => resume_unwind(panic_payload),
+			
+			Err(kill_error) => Err(kill_error),
+		}
+	}
+}
@@ -0,0 +1,37 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Whether this thread is inside an allocator operation; see `InAllocatorGuard`.
+///
+/// A `#[thread_local]` static rather than `thread_local!()` so that it can be accessed from a signal handler.
+#[thread_local]
+static InAllocator: Cell<bool> = Cell::new(false);
+
+/// Marks this thread as being inside an allocator operation until dropped, so that a `PreemptionTimer` does not preempt it.
+///
+/// A `GlobalThreadAndCoroutineSwitchableAllocator` used with a `PreemptionTimer` must hold one for the whole of every `alloc()`, `dealloc()` and `realloc()`, as these may use the thread-local or global allocator even whilst the coroutine-local allocator is in use.
+#[derive(Debug)]
+pub struct InAllocatorGuard(bool);
+
+impl Drop for InAllocatorGuard
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		compiler_fence(SeqCst);
+		InAllocator.set(self.0);
+	}
+}
+
+impl InAllocatorGuard
+{
+	/// Enters an allocator operation; operations may nest.
+	#[inline(always)]
+	pub fn enter() -> Self
+	{
+		let this = Self(InAllocator.replace(true));
+		compiler_fence(SeqCst);
+		this
+	}
+}
@@ -0,0 +1,61 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// The preemptible region, if any, of the coroutine running on this thread.
+///
+/// A `#[thread_local]` static rather than `thread_local!()` so that it can be accessed from a signal handler.
+#[thread_local]
+static CurrentPreemptibleRegion: Cell<Option<PreemptibleRegion>> = Cell::new(None);
+
+/// A region of a coroutine's code which may be preempted; see `Yielder::preemptible()`.
+///
+/// Type-erased so it can be used from a signal handler.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PreemptibleRegion
+{
+	yielder: *mut (),
+	yield_to_parent: unsafe fn(*mut ()) -> bool,
+	global_allocator: *const (),
+	is_coroutine_local_allocator_in_use: unsafe fn(*const ()) -> bool,
+	ticks: u8,
+	preempting: bool,
+	killed: bool,
+}
+
+impl PreemptibleRegion
+{
+	/// `yield_to_parent` is passed `yielder` and returns `false` if killed.
+	#[inline(always)]
+	pub(crate) fn new<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(yielder: *mut (), yield_to_parent: unsafe fn(*mut ()) -> bool, global_allocator: &'static GTACSA) -> Self
+	{
+		Self
+		{
+			yielder,
+			yield_to_parent,
+			global_allocator: global_allocator as *const GTACSA as *const (),
+			is_coroutine_local_allocator_in_use: is_coroutine_local_allocator_in_use::<CoroutineHeapSize, GTACSA>,
+			ticks: 0,
+			preempting: false,
+			killed: false,
+		}
+	}
+	
+	/// A region must still be running after a whole timer period (ie a second tick) to be preempted.
+	#[inline(always)]
+	fn should_preempt(&mut self) -> bool
+	{
+		if self.preempting || self.killed
+		{
+			return false
+		}
+		
+		if self.ticks == 0
+		{
+			self.ticks = 1;
+			return false
+		}
+		
+		unsafe { (self.is_coroutine_local_allocator_in_use)(self.global_allocator) }
+	}
+}
@@ -0,0 +1,44 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Leaves the current preemptible region when dropped, even if unwinding from a panic.
+pub(crate) struct PreemptibleRegionGuard(bool);
+
+impl Drop for PreemptibleRegionGuard
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		if self.0
+		{
+			CurrentPreemptibleRegion.set(None);
+			compiler_fence(SeqCst);
+		}
+	}
+}
+
+impl PreemptibleRegionGuard
+{
+	/// Preemptible regions do not nest; entering a region whilst in a region does nothing.
+	#[inline(always)]
+	pub(crate) fn enter(preemptible_region: PreemptibleRegion) -> Self
+	{
+		let entered = CurrentPreemptibleRegion.get().is_none();
+		if entered
+		{
+			compiler_fence(SeqCst);
+			CurrentPreemptibleRegion.set(Some(preemptible_region));
+			compiler_fence(SeqCst);
+		}
+		Self(entered)
+	}
+	
+	/// Returns `true` if the coroutine was killed whilst preempted.
+	#[inline(always)]
+	pub(crate) fn leave(self) -> bool
+	{
+		compiler_fence(SeqCst);
+		self.0 && CurrentPreemptibleRegion.get().map(|preemptible_region| preemptible_region.killed).unwrap_or(false)
+	}
+}
@@ -0,0 +1,119 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An opt-in, per-thread, timer which preempts coroutines running in a `Yielder::preemptible()` region for longer than `slice`.
+///
+/// Uses `timer_create()` with the thread's CPU-time clock, delivering `signal_number` (eg `SIGURG`) to this thread only.
+/// The signal's handler is replaced for the whole process; it must differ from the stack sample signal of any `Watchdog` (eg `SIGUSR2`).
+///
+/// The `GlobalThreadAndCoroutineSwitchableAllocator` must hold an `InAllocatorGuard` during every allocator operation.
+///
+/// The preempted coroutine's full register state, including SSE and AVX state, is saved using `XSAVE` on its stack; allow for this (up to a few KiB) when choosing a stack size.
+#[derive(Debug)]
+pub struct PreemptionTimer
+{
+	timer_identifier: timer_t,
+}
+
+impl Drop for PreemptionTimer
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		unsafe { timer_delete(self.timer_identifier) };
+	}
+}
+
+impl PreemptionTimer
+{
+	/// Creates and starts a timer for the current thread.
+	///
+	/// Fails if `XSAVE` is not supported or enabled by the operating system, or if `signal_number` already has a handler other than that of a `PreemptionTimer`.
+	#[inline(always)]
+	pub fn new(signal_number: c_int, slice: Duration) -> io::Result<Self>
+	{
+		Self::initialize_xsave_area_size()?;
+		Self::install_signal_handler(signal_number)?;
+		
+		let mut signal_event: sigevent = unsafe { zeroed() };
+		signal_event.sigev_notify = SIGEV_THREAD_ID;
+		signal_event.sigev_signo = signal_number;
+		signal_event.sigev_notify_thread_id = unsafe { syscall(SYS_gettid) } as c_int;
+		
+		let mut timer_identifier: timer_t = null_mut();
+		if unlikely!(unsafe { timer_create(CLOCK_THREAD_CPUTIME_ID, &mut signal_event, &mut timer_identifier) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		let this = Self
+		{
+			timer_identifier,
+		};
+		
+		let period = timespec
+		{
+			tv_sec: slice.as_secs() as _,
+			tv_nsec: slice.subsec_nanos() as _,
+		};
+		let interval = itimerspec
+		{
+			it_interval: period,
+			it_value: period,
+		};
+		if unlikely!(unsafe { timer_settime(timer_identifier, 0, &interval, null_mut()) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		
+		Ok(this)
+	}
+	
+	#[inline(always)]
+	fn initialize_xsave_area_size() -> io::Result<()>
+	{
+		const OSXSAVE: u32 = 1 << 27;
+		
+		if unlikely!(unsafe { __cpuid(1) }.ecx & OSXSAVE == 0)
+		{
+			return Err(io::Error::new(io::ErrorKind::Other, "XSAVE is not enabled"))
+		}
+		
+		let size_for_features_enabled_in_xcr0 = unsafe { __cpuid_count(0xD, 0) }.ebx as u64;
+		unsafe { context_coroutine_xsave_area_size = (size_for_features_enabled_in_xcr0 + 63) & !63 };
+		Ok(())
+	}
+	
+	/// Fails if `signal_number` already has a handler other than this one (eg it is in use by another library or by the other of `Watchdog` and `PreemptionTimer`).
+	#[inline(always)]
+	fn install_signal_handler(signal_number: c_int) -> io::Result<()>
+	{
+		let mut previous_signal_action: sigaction = unsafe { zeroed() };
+		if unlikely!(unsafe { sigaction(signal_number, null(), &mut previous_signal_action) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		
+		let handler = preemption_signal_handler as usize;
+		let previous_handler = previous_signal_action.sa_sigaction;
+		if previous_handler == handler
+		{
+			return Ok(())
+		}
+		if unlikely!(previous_handler != SIG_DFL)
+		{
+			return Err(io::Error::new(io::ErrorKind::AlreadyExists, "signal already has a handler"))
+		}
+		
+		let mut signal_action: sigaction = unsafe { zeroed() };
+		signal_action.sa_sigaction = handler;
+		signal_action.sa_flags = SA_SIGINFO | SA_RESTART;
+		unsafe { sigemptyset(&mut signal_action.sa_mask) };
+		
+		if unlikely!(unsafe { sigaction(signal_number, &signal_action, null_mut()) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		Ok(())
+	}
+}
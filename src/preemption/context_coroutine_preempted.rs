@@ -0,0 +1,31 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Called by `context_coroutine_preemption_trampoline` on the preempted coroutine's stack, with all registers saved.
+///
+/// Firstly writes the interrupted instruction pointer to `return_address`, before another coroutine on this thread can be preempted.
+///
+/// Yields `ChildOutcome::BudgetExhausted` to the parent; whilst the parent runs, there is no current preemptible region, so that other coroutines on this thread can be preempted.
+#[no_mangle]
+unsafe extern "C" fn context_coroutine_preempted(return_address: *mut u64)
+{
+	return_address.write(PreemptedInstructionPointer.get());
+	compiler_fence(SeqCst);
+	
+	let mut preemptible_region = match CurrentPreemptibleRegion.replace(None)
+	{
+		None => return,
+		
+		Some(preemptible_region) => preemptible_region,
+	};
+	compiler_fence(SeqCst);
+	
+	let resumed = (preemptible_region.yield_to_parent)(preemptible_region.yielder);
+	
+	preemptible_region.ticks = 0;
+	preemptible_region.preempting = false;
+	preemptible_region.killed = !resumed;
+	compiler_fence(SeqCst);
+	CurrentPreemptibleRegion.set(Some(preemptible_region));
+}
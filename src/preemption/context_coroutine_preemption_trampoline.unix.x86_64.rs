@@ -0,0 +1,91 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Size of the `XSAVE` area for the features enabled in `XCR0`, rounded up to a multiple of 64 bytes; set by `PreemptionTimer::new()`.
+#[no_mangle]
+static mut context_coroutine_xsave_area_size: u64 = 0;
+
+extern "C"
+{
+	/// Entered, on the preempted coroutine's stack, when `preemption_signal_handler()` returns.
+	///
+	/// Skips the interrupted code's red zone and reserves space for the interrupted instruction pointer, which `context_coroutine_preempted()` fills in.
+	///
+	/// Saves all caller-saved registers, the flags and the full extended state (x87, SSE, AVX, etc) using `XSAVE`, calls `context_coroutine_preempted()`, restores everything and returns to the interrupted instruction.
+	/// Callee-saved registers are preserved by `context_coroutine_preempted()`, as for any function obeying the System V ABI.
+	fn context_coroutine_preemption_trampoline();
+}
+
+global_asm!
+{r#"
+    .intel_syntax noprefix
+	.text
+	.p2align 4, 0x90
+	.hidden context_coroutine_preemption_trampoline
+	.globl context_coroutine_preemption_trampoline
+	context_coroutine_preemption_trampoline:
+
+		// (1) Skip the interrupted code's red zone and reserve space for the interrupted instruction pointer; `lea` does not change the flags.
+		lea rsp, [rsp - 136]
+
+		// (2) Save flags and caller-saved general purpose registers; rbx is saved as it is used to hold the stack pointer.
+		pushfq
+		push rax
+		push rcx
+		push rdx
+		push rsi
+		push rdi
+		push r8
+		push r9
+		push r10
+		push r11
+		push rbx
+
+		// (3) Reserve a 64-byte aligned `XSAVE` area.
+		mov rbx, rsp
+		sub rsp, qword ptr context_coroutine_xsave_area_size[rip]
+		and rsp, -64
+
+		// Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1, Section 13.4.2 "XSAVE Header": `XRSTOR` raises `#GP` if bytes 8 to 63 of the header are not zero; `XSAVE` does not write them.
+		xor eax, eax
+		mov qword ptr [rsp + 512], rax
+		mov qword ptr [rsp + 520], rax
+		mov qword ptr [rsp + 528], rax
+		mov qword ptr [rsp + 536], rax
+		mov qword ptr [rsp + 544], rax
+		mov qword ptr [rsp + 552], rax
+		mov qword ptr [rsp + 560], rax
+		mov qword ptr [rsp + 568], rax
+
+		// (4) Save all extended state enabled in `XCR0`.
+		mov eax, -1
+		mov edx, -1
+		xsave64 [rsp]
+
+		// (5) Yield to the parent, passing the address of the space reserved in (1), above the 11 registers saved in (2); the System V ABI requires the direction flag to be clear on function entry.
+		lea rdi, [rbx + 88]
+		cld
+		call context_coroutine_preempted
+
+		// (6) Restore extended state, general purpose registers and flags.
+		mov eax, -1
+		mov edx, -1
+		xrstor64 [rsp]
+
+		mov rsp, rbx
+		pop rbx
+		pop r11
+		pop r10
+		pop r9
+		pop r8
+		pop rdi
+		pop rsi
+		pop rdx
+		pop rcx
+		pop rax
+		popfq
+
+		// (7) Return to the interrupted instruction and then skip back over the red zone.
+		ret 128
+"#}
@@ -0,0 +1,20 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Only a coroutine interrupted whilst using its coroutine-local allocator, and not inside an allocator operation (see `InAllocatorGuard`), can be preempted: switching back to the parent switches to a different allocator, so the interrupted allocator's state is left untouched until the coroutine is resumed.
+///
+/// The thread-local and global allocators, by contrast, are shared with the parent and might be in the middle of an operation.
+///
+/// Called from a signal handler, so only reads the current allocator in use.
+#[inline(always)]
+unsafe fn is_coroutine_local_allocator_in_use<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(global_allocator: *const ()) -> bool
+{
+	if InAllocator.get()
+	{
+		return false
+	}
+	
+	let global_allocator = &*(global_allocator as *const GTACSA);
+	matches!(global_allocator.save_current_allocator_in_use(), CurrentAllocatorInUse::CoroutineLocal)
+}
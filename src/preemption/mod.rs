@@ -0,0 +1,46 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+use libc::CLOCK_THREAD_CPUTIME_ID;
+use libc::SA_RESTART;
+use libc::SA_SIGINFO;
+use libc::SIGEV_THREAD_ID;
+use libc::SIG_DFL;
+use libc::SYS_gettid;
+use libc::REG_RIP;
+use libc::c_int;
+use libc::c_void;
+use libc::itimerspec;
+use libc::sigaction;
+use libc::sigemptyset;
+use libc::sigevent;
+use libc::siginfo_t;
+use libc::syscall;
+use libc::timer_create;
+use libc::timer_delete;
+use libc::timer_settime;
+use libc::timer_t;
+use libc::timespec;
+use libc::ucontext_t;
+use std::arch::x86_64::__cpuid;
+use std::arch::x86_64::__cpuid_count;
+use std::io;
+use std::mem::zeroed;
+use std::ptr::null;
+use std::ptr::null_mut;
+use std::sync::atomic::compiler_fence;
+use std::sync::atomic::Ordering::SeqCst;
+
+
+include!("context_coroutine_preempted.rs");
+include!("context_coroutine_preemption_trampoline.unix.x86_64.rs");
+include!("is_coroutine_local_allocator_in_use.rs");
+include!("preemption_signal_handler.rs");
+
+
+include!("InAllocatorGuard.rs");
+include!("PreemptibleRegion.rs");
+include!("PreemptibleRegionGuard.rs");
+include!("PreemptionTimer.rs");
@@ -0,0 +1,36 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// The instruction pointer interrupted by `preemption_signal_handler()`, read by `context_coroutine_preempted()`.
+#[thread_local]
+static PreemptedInstructionPointer: Cell<u64> = Cell::new(0);
+
+/// If the running coroutine should be preempted, changes the interrupted context so that, when the signal handler returns, it continues at `context_coroutine_preemption_trampoline` rather than at the interrupted instruction.
+///
+/// The interrupted instruction pointer is saved in `PreemptedInstructionPointer` rather than on the interrupted stack, as the kernel's signal frame occupies the stack below the red zone whilst this handler runs.
+extern "C" fn preemption_signal_handler(_signal_number: c_int, _signal_information: *mut siginfo_t, user_context: *mut c_void)
+{
+	let mut preemptible_region = match CurrentPreemptibleRegion.get()
+	{
+		None => return,
+		
+		Some(preemptible_region) => preemptible_region,
+	};
+	
+	let should_preempt = preemptible_region.should_preempt();
+	if should_preempt
+	{
+		preemptible_region.preempting = true;
+	}
+	CurrentPreemptibleRegion.set(Some(preemptible_region));
+	
+	if !should_preempt
+	{
+		return
+	}
+	
+	let general_registers = unsafe { &mut (*(user_context as *mut ucontext_t)).uc_mcontext.gregs };
+	PreemptedInstructionPointer.set(general_registers[REG_RIP as usize] as u64);
+	general_registers[REG_RIP as usize] = context_coroutine_preemption_trampoline as usize as i64;
+}
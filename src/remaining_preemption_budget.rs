@@ -0,0 +1,33 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+thread_local!
+{
+	static CurrentRemainingPreemptionBudget: Cell<RemainingPreemptionBudget> = Cell::new(RemainingPreemptionBudget::Unlimited);
+}
+
+#[inline(always)]
+fn replace_remaining_preemption_budget(remaining_preemption_budget: RemainingPreemptionBudget) -> RemainingPreemptionBudget
+{
+	CurrentRemainingPreemptionBudget.with(|current| current.replace(remaining_preemption_budget))
+}
+
+/// Returns `true` if the running coroutine's budget is exhausted.
+#[inline(always)]
+fn consume_remaining_preemption_budget(operations: u64) -> bool
+{
+	CurrentRemainingPreemptionBudget.with(|current|
+	{
+		let mut remaining_preemption_budget = current.get();
+		let exhausted = remaining_preemption_budget.consume(operations);
+		current.set(remaining_preemption_budget);
+		exhausted
+	})
+}
+
+#[inline(always)]
+fn time_stamp_counter() -> u64
+{
+	unsafe { _rdtsc() }
+}
@@ -0,0 +1,11 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A node in the lock-free stack of remote wake ups.
+struct RemoteWakeUp<Payload: Send>
+{
+	next: *mut RemoteWakeUp<Payload>,
+	coroutine_instance_handle: CoroutineInstanceHandle,
+	payload: Payload,
+}
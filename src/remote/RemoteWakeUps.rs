@@ -0,0 +1,84 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Wake ups of coroutines on this thread from other threads, eg when a DNS lookup or blocking disk call has finished.
+///
+/// Register `as_raw_fd()` for readability with the thread's reactor (eg epoll or io_uring); when it is readable, call `resume()` (or `drain()`).
+#[derive(Debug)]
+pub struct RemoteWakeUps<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	shared: RemoteWaker<Payload, CoroutineHeapSize, GTACSA>,
+	not_send_or_sync: PhantomData<*const ()>,
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> AsRawFd for RemoteWakeUps<Payload, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn as_raw_fd(&self) -> RawFd
+	{
+		(self.shared.0).event_file_descriptor
+	}
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RemoteWakeUps<Payload, CoroutineHeapSize, GTACSA>
+{
+	/// New instance; creates a non-blocking eventfd.
+	#[inline(always)]
+	pub fn new(global_allocator: &'static GTACSA) -> io::Result<Self>
+	{
+		Ok
+		(
+			Self
+			{
+				shared: RemoteWaker(RemoteWakeUpsShared::new(global_allocator)?),
+				not_send_or_sync: PhantomData,
+			}
+		)
+	}
+	
+	/// A waker that can be sent to other threads.
+	#[inline(always)]
+	pub fn remote_waker(&self) -> RemoteWaker<Payload, CoroutineHeapSize, GTACSA>
+	{
+		self.shared.clone()
+	}
+	
+	/// Takes all wake ups, in the order they were made, passing each to `woken`.
+	///
+	/// Handles may be stale; use `resume()` to discard stale handles and resume live coroutines.
+	///
+	/// If `woken` panics, the wake ups not yet passed to it are kept for the next call.
+	#[inline(always)]
+	pub fn drain(&self, woken: impl FnMut(CoroutineInstanceHandle, Payload))
+	{
+		let shared = &self.shared.0;
+		shared.acknowledge();
+		shared.free(shared.take_all(), woken)
+	}
+	
+	/// Takes all wake ups, in the order they were made, and resumes each live coroutine with its payload, passing the outcome to `resumed`.
+	///
+	/// The payloads of wake ups for coroutines that have since completed or been cancelled (ie whose generation no longer matches) are dropped.
+	#[inline(always)]
+	pub fn resume<StackSize: MemorySize, C: Coroutine<ResumeArguments=Payload>, CoroutineInformation: 'static + Sized, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, mut resumed: impl FnMut(&mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, CoroutineInstanceHandle, ResumeOutcome<C::Yields, C::Complete>))
+	{
+		self.drain(|coroutine_instance_handle, payload| if let Some(coroutine_instance_pointer) = coroutine_manager.live_coroutine_instance_pointer(coroutine_instance_handle)
+		{
+			let resume_outcome = coroutine_manager.resume_coroutine(coroutine_instance_pointer, payload);
+			resumed(coroutine_manager, coroutine_instance_handle, resume_outcome)
+		})
+	}
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RemoteWakeUps<(), CoroutineHeapSize, GTACSA>
+{
+	/// Takes all wake ups, in the order they were made, and wakes each coroutine on `run_queue`.
+	///
+	/// Used when coroutines wait for remote work, eg using an `Offloader`, rather than being resumed directly with a payload.
+	#[inline(always)]
+	pub fn wake_on_run_queue(&self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>)
+	{
+		self.drain(|coroutine_instance_handle, ()| run_queue.wake(coroutine_instance_handle))
+	}
+}
@@ -0,0 +1,182 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// State shared between the owning thread and all remote wakers.
+///
+/// Wake ups are pushed onto a lock-free (Treiber) stack; the owning thread takes the entire stack at once.
+/// All nodes are allocated and freed using the global allocator, as they cross threads.
+struct RemoteWakeUpsShared<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	global_allocator: &'static GTACSA,
+	head: AtomicPtr<RemoteWakeUp<Payload>>,
+	event_file_descriptor: RawFd,
+	marker: PhantomData<CoroutineHeapSize>,
+}
+
+unsafe impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync> Send for RemoteWakeUpsShared<Payload, CoroutineHeapSize, GTACSA>
+{
+}
+
+unsafe impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize> + Sync> Sync for RemoteWakeUpsShared<Payload, CoroutineHeapSize, GTACSA>
+{
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for RemoteWakeUpsShared<Payload, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.free(self.take_all(), |_, _| ());
+		unsafe { close(self.event_file_descriptor) };
+	}
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RemoteWakeUpsShared<Payload, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn new(global_allocator: &'static GTACSA) -> io::Result<Arc<Self>>
+	{
+		let event_file_descriptor = unsafe { eventfd(0, EFD_NONBLOCK | EFD_CLOEXEC) };
+		if unlikely!(event_file_descriptor == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		
+		let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+		Ok
+		(
+			Arc::new
+			(
+				Self
+				{
+					global_allocator,
+					head: AtomicPtr::new(null_mut()),
+					event_file_descriptor,
+					marker: PhantomData,
+				}
+			)
+		)
+	}
+	
+	/// Called from any thread.
+	#[inline(always)]
+	fn push(&self, coroutine_instance_handle: CoroutineInstanceHandle, payload: Payload)
+	{
+		let node =
+		{
+			let _restore = RestoreCurrentAllocatorInUse::switch(self.global_allocator, CurrentAllocatorInUse::Global);
+			Box::into_raw
+			(
+				Box::new
+				(
+					RemoteWakeUp
+					{
+						next: null_mut(),
+						coroutine_instance_handle,
+						payload,
+					}
+				)
+			)
+		};
+		
+		self.push_node(node);
+		self.signal()
+	}
+	
+	#[inline(always)]
+	fn push_node(&self, node: *mut RemoteWakeUp<Payload>)
+	{
+		let mut head = self.head.load(Relaxed);
+		loop
+		{
+			unsafe { (*node).next = head };
+			match self.head.compare_exchange_weak(head, node, Release, Relaxed)
+			{
+				Ok(_) => break,
+				
+				Err(current_head) => head = current_head,
+			}
+		}
+	}
+	
+	/// Adds one to the eventfd's counter.
+	///
+	/// Fails only if the counter would overflow, in which case the owning thread has yet to read it and so will still be woken.
+	#[inline(always)]
+	fn signal(&self)
+	{
+		let increment: u64 = 1;
+		loop
+		{
+			let result = unsafe { write(self.event_file_descriptor, &increment as *const u64 as *const c_void, size_of::<u64>()) };
+			if likely!(result != -1) || io::Error::last_os_error().raw_os_error() != Some(EINTR)
+			{
+				return
+			}
+		}
+	}
+	
+	/// Resets the eventfd's counter to zero.
+	///
+	/// Fails only if the counter is already zero (`EAGAIN`).
+	#[inline(always)]
+	fn acknowledge(&self)
+	{
+		let mut counter: u64 = 0;
+		loop
+		{
+			let result = unsafe { read(self.event_file_descriptor, &mut counter as *mut u64 as *mut c_void, size_of::<u64>()) };
+			if likely!(result != -1) || io::Error::last_os_error().raw_os_error() != Some(EINTR)
+			{
+				return
+			}
+		}
+	}
+	
+	/// Takes all wake ups, most recent first.
+	#[inline(always)]
+	fn take_all(&self) -> *mut RemoteWakeUp<Payload>
+	{
+		self.head.swap(null_mut(), Acquire)
+	}
+	
+	/// Frees all wake ups in the order they were pushed, passing each to `woken`.
+	///
+	/// If `woken` panics, the wake ups not yet passed to it are pushed back, in order, to be taken again.
+	fn free(&self, most_recent_first: *mut RemoteWakeUp<Payload>, mut woken: impl FnMut(CoroutineInstanceHandle, Payload))
+	{
+		let mut oldest_first = null_mut();
+		let mut node = most_recent_first;
+		while !node.is_null()
+		{
+			let next = unsafe { (*node).next };
+			unsafe { (*node).next = oldest_first };
+			oldest_first = node;
+			node = next;
+		}
+		
+		let mut node = oldest_first;
+		while !node.is_null()
+		{
+			let RemoteWakeUp { next, coroutine_instance_handle, payload } =
+			{
+				let _restore = RestoreCurrentAllocatorInUse::switch(self.global_allocator, CurrentAllocatorInUse::Global);
+				*unsafe { Box::from_raw(node) }
+			};
+			node = next;
+			
+			if let Err(panic_information) = catch_unwind(AssertUnwindSafe(|| woken(coroutine_instance_handle, payload)))
+			{
+				while !node.is_null()
+				{
+					let next = unsafe { (*node).next };
+					self.push_node(node);
+					node = next;
+				}
+				self.signal();
+				resume_unwind(panic_information)
+			}
+		}
+	}
+}
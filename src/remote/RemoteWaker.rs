@@ -0,0 +1,39 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Wakes coroutines owned by another thread; created using `RemoteWakeUps::remote_waker()`.
+///
+/// Can be cloned and sent to any thread if `GTACSA` is `Sync`.
+pub struct RemoteWaker<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(Arc<RemoteWakeUpsShared<Payload, CoroutineHeapSize, GTACSA>>);
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for RemoteWaker<Payload, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "RemoteWaker({})", self.0.event_file_descriptor)
+	}
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Clone for RemoteWaker<Payload, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self(self.0.clone())
+	}
+}
+
+impl<Payload: Send, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> RemoteWaker<Payload, CoroutineHeapSize, GTACSA>
+{
+	/// Wakes the coroutine with `coroutine_instance_handle`, which will be resumed with `payload` by the owning thread.
+	///
+	/// Never blocks.
+	/// If the coroutine has completed (or been cancelled) by the time the owning thread drains its wake ups, `payload` is dropped.
+	#[inline(always)]
+	pub fn wake(&self, coroutine_instance_handle: CoroutineInstanceHandle, payload: Payload)
+	{
+		self.0.push(coroutine_instance_handle, payload)
+	}
+}
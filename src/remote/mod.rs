@@ -0,0 +1,28 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+use libc::EFD_CLOEXEC;
+use libc::EFD_NONBLOCK;
+use libc::EINTR;
+use libc::c_void;
+use libc::close;
+use libc::eventfd;
+use libc::read;
+use libc::write;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::io::RawFd;
+use std::ptr::null_mut;
+use std::sync::Arc;
+use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::Ordering::Acquire;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::Ordering::Release;
+
+
+include!("RemoteWakeUp.rs");
+include!("RemoteWakeUps.rs");
+include!("RemoteWakeUpsShared.rs");
+include!("RemoteWaker.rs");
@@ -0,0 +1,204 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A structured concurrency scope (nursery): a coroutine spawns children into a scope and then waits for all of them to finish.
+///
+/// * A scope can not finish until every child has finished.
+/// * The first child to panic (or fail to start) causes all other children to be cancelled; the panic is returned to the scope's owner.
+/// * Cancelling or dropping a scope cancels all of its children; they are cancelled by the `CoroutineManager` before control returns from it, so no child runs again once the scope's owner has cancelled or dropped it, including when its owner is killed.
+///
+/// Children are started by the owner of the `CoroutineManager` when it calls `CoroutineManager::process_scope_requests()`.
+#[derive(Debug)]
+pub struct Scope<C: Coroutine, CoroutineInformation: 'static + Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	scope_requests: Rc<ScopeRequests<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>,
+	scope_state: Rc<RefCell<ScopeState<C::Complete, CoroutineHeapSize, GTACSA>>>,
+}
+
+impl<C: Coroutine, CoroutineInformation: 'static + Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for Scope<C, CoroutineInformation, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.cancel()
+	}
+}
+
+impl<C: Coroutine, CoroutineInformation: 'static + Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Scope<C, CoroutineInformation, CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	///
+	/// # Safety
+	///
+	/// The `C::Complete` values of children must not own memory allocated from their coroutine-local heap, as they are dropped after that heap has been recycled.
+	#[inline(always)]
+	pub unsafe fn new(scope_requests: &Rc<ScopeRequests<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>) -> Self
+	{
+		let scope_state = scope_requests.run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				RefCell::new
+				(
+					ScopeState
+					{
+						pending_spawns: 0,
+						children: Vec::new(),
+						first_failure: None,
+						cancelled: false,
+						waiting: WaitQueue::default(),
+					}
+				)
+			)
+		});
+		
+		Self
+		{
+			scope_requests: scope_requests.clone(),
+			scope_state,
+		}
+	}
+	
+	/// Requests that a child coroutine is started in this scope.
+	///
+	/// Returns `false` if this scope has been cancelled or a child has failed.
+	#[inline(always)]
+	pub fn spawn(&self, coroutine_information: CoroutineInformation, start_arguments: C::StartArguments) -> bool
+	{
+		{
+			let mut scope_state = self.scope_state.borrow_mut();
+			if scope_state.cancelled || scope_state.first_failure.is_some()
+			{
+				return false
+			}
+			scope_state.pending_spawns += 1;
+		}
+		
+		self.scope_requests.push
+		(
+			ScopeRequest::Spawn
+			{
+				scope_state: self.scope_state.clone(),
+				coroutine_information,
+				start_arguments,
+			}
+		);
+		true
+	}
+	
+	/// Cancels all children, including those not yet started.
+	#[inline(always)]
+	pub fn cancel(&self)
+	{
+		self.scope_state.borrow_mut().cancelled = true;
+		self.cancel_children()
+	}
+	
+	/// Suspends the coroutine of `yielder` until every child has finished.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	///
+	/// The `Complete` values of children are discarded.
+	#[inline(always)]
+	pub fn wait<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<(), ScopeError<E>>
+	{
+		loop
+		{
+			if self.reap_finished_children()
+			{
+				self.cancel_children()
+			}
+			
+			if self.scope_state.borrow().is_finished()
+			{
+				return match self.scope_state.borrow_mut().first_failure.take()
+				{
+					None => Ok(()),
+					
+					Some(Ok(panic_information)) => Err(ScopeError::ChildPanicked(panic_information)),
+					
+					Some(Err(AllocError)) => Err(ScopeError::CouldNotSpawnChild),
+				}
+			}
+			
+			if !self.wait_for_any_child(yielder, yields())
+			{
+				self.cancel();
+				return Err(ScopeError::Killed(kill_error))
+			}
+		}
+	}
+	
+	/// Returns `true` if a child failed for the first time.
+	#[inline(always)]
+	fn reap_finished_children(&self) -> bool
+	{
+		let mut scope_state = self.scope_state.borrow_mut();
+		let mut first_failure = false;
+		
+		let mut index = 0;
+		while index < scope_state.children.len()
+		{
+			if !scope_state.children[index].1.is_finished()
+			{
+				index += 1;
+				continue
+			}
+			
+			let (_coroutine_instance_handle, join_handle) = scope_state.children.swap_remove(index);
+			if let Ok(JoinOutcome::Panicked(panic_information)) = join_handle.try_join()
+			{
+				first_failure |= scope_state.failed(Ok(panic_information));
+			}
+		}
+		
+		first_failure
+	}
+	
+	#[inline(always)]
+	fn cancel_children(&self)
+	{
+		let scope_state = self.scope_state.borrow();
+		for &(coroutine_instance_handle, _) in scope_state.children.iter()
+		{
+			self.scope_requests.push(ScopeRequest::Cancel(coroutine_instance_handle))
+		}
+	}
+	
+	/// Waits in the wait queue of the scope (woken when a child is started) and of every child (woken when it finishes).
+	///
+	/// Returns `false` if killed.
+	#[inline(always)]
+	fn wait_for_any_child<ResumeArguments, Yields, Complete>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, yields: Yields) -> bool
+	{
+		let run_queue = &self.scope_requests.run_queue;
+		let coroutine_instance_handle = yielder.coroutine_instance_handle();
+		
+		{
+			let mut scope_state = self.scope_state.borrow_mut();
+			scope_state.waiting.push(run_queue, coroutine_instance_handle);
+			for (_, join_handle) in scope_state.children.iter()
+			{
+				join_handle.0.slot.borrow_mut().waiting.push(run_queue, coroutine_instance_handle)
+			}
+		}
+		
+		run_queue.started_waiting(coroutine_instance_handle, WaitingOn::Scope(&*self.scope_state as *const RefCell<ScopeState<C::Complete, CoroutineHeapSize, GTACSA>> as usize));
+		let killed = yielder.suspend(yields).is_none();
+		run_queue.stopped_waiting(coroutine_instance_handle);
+		
+		{
+			let mut scope_state = self.scope_state.borrow_mut();
+			scope_state.waiting.remove(coroutine_instance_handle);
+			for (_, join_handle) in scope_state.children.iter()
+			{
+				join_handle.0.slot.borrow_mut().waiting.remove(coroutine_instance_handle);
+			}
+		}
+		
+		!killed
+	}
+}
@@ -0,0 +1,19 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Why a scope finished unsuccessfully.
+#[derive(Debug)]
+pub enum ScopeError<E>
+{
+	/// A child panicked; the remaining children were cancelled.
+	///
+	/// Use `std::panic::resume_unwind()` to re-raise the panic in the scope's owner.
+	ChildPanicked(Box<dyn Any + Send + 'static>),
+	
+	/// A child could not be started because there was not enough memory; the remaining children were cancelled.
+	CouldNotSpawnChild,
+	
+	/// The scope's owner was killed whilst waiting; the children have been cancelled.
+	Killed(E),
+}
@@ -0,0 +1,79 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Requests made by scopes to start and cancel coroutines.
+///
+/// A coroutine can not start or cancel other coroutines itself, as the `CoroutineManager` is in use whilst it runs.
+/// Instead, requests are queued here.
+/// Requests to start coroutines are processed by the owner of the `CoroutineManager` using `CoroutineManager::process_scope_requests()` whenever a coroutine yields.
+/// Requests to cancel coroutines are processed by the `CoroutineManager` itself as soon as control is transferred back from a coroutine.
+///
+/// Use one instance for each `CoroutineManager` and pass it to `CoroutineManager::set_scope_requests()`.
+#[derive(Debug)]
+pub struct ScopeRequests<C: Coroutine, CoroutineInformation: 'static + Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	pub(crate) run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	requests: RefCell<VecDeque<ScopeRequest<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>>,
+}
+
+impl<C: Coroutine, CoroutineInformation: 'static + Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> ScopeRequests<C, CoroutineInformation, CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					requests: RefCell::new(VecDeque::new()),
+				}
+			)
+		})
+	}
+	
+	/// Are there no requests to process?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.requests.borrow().is_empty()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn push(&self, scope_request: ScopeRequest<C, CoroutineInformation, CoroutineHeapSize, GTACSA>)
+	{
+		self.run_queue.outside_coroutine_local_heap(|| self.requests.borrow_mut().push_back(scope_request))
+	}
+	
+	#[inline(always)]
+	pub(crate) fn pop(&self) -> Option<ScopeRequest<C, CoroutineInformation, CoroutineHeapSize, GTACSA>>
+	{
+		self.requests.borrow_mut().pop_front()
+	}
+	
+	/// Removes the oldest request to cancel a coroutine, leaving requests to start coroutines in place.
+	#[inline(always)]
+	pub(crate) fn pop_cancel(&self) -> Option<CoroutineInstanceHandle>
+	{
+		let mut requests = self.requests.borrow_mut();
+		let index = requests.iter().position(|scope_request| match scope_request
+		{
+			ScopeRequest::Cancel(_) => true,
+			
+			ScopeRequest::Spawn { .. } => false,
+		})?;
+		
+		match requests.remove(index)
+		{
+			Some(ScopeRequest::Cancel(coroutine_instance_handle)) => Some(coroutine_instance_handle),
+			
+			_ => unsafe { unreachable() },
+		}
+	}
+}
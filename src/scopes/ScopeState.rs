@@ -0,0 +1,37 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+pub(crate) struct ScopeState<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	pub(crate) pending_spawns: usize,
+	pub(crate) children: Vec<(CoroutineInstanceHandle, JoinHandle<Complete, CoroutineHeapSize, GTACSA>)>,
+	pub(crate) first_failure: Option<Result<Box<dyn Any + Send + 'static>, AllocError>>,
+	pub(crate) cancelled: bool,
+	pub(crate) waiting: WaitQueue,
+}
+
+impl<Complete: Sized, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> ScopeState<Complete, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn is_finished(&self) -> bool
+	{
+		self.pending_spawns == 0 && self.children.is_empty()
+	}
+	
+	/// Records the first failure; returns `true` if it was the first.
+	#[inline(always)]
+	pub(crate) fn failed(&mut self, failure: Result<Box<dyn Any + Send + 'static>, AllocError>) -> bool
+	{
+		if self.first_failure.is_none()
+		{
+			self.first_failure = Some(failure);
+			true
+		}
+		else
+		{
+			false
+		}
+	}
+}
@@ -0,0 +1,28 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A `Supervisor` gives up if there are more than `maximum_restarts` in any `period`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RestartIntensity
+{
+	/// Maximum number of restarts.
+	pub maximum_restarts: usize,
+	
+	/// Sliding period in which restarts are counted.
+	pub period: Duration,
+}
+
+impl Default for RestartIntensity
+{
+	/// Erlang's default of 1 restart in 5 seconds.
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			maximum_restarts: 1,
+			period: Duration::from_secs(5),
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Which children a `Supervisor` restarts when a child fails.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RestartStrategy
+{
+	/// Only the failed child is restarted.
+	OneForOne,
+	
+	/// All other children are cancelled, then all children are restarted.
+	OneForAll,
+	
+	/// Children after the failed child (in the order they were defined) are cancelled, then the failed child and those after it are restarted.
+	RestForOne,
+}
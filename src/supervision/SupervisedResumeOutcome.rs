@@ -0,0 +1,26 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Outcome of resuming a supervised child.
+#[derive(Debug)]
+pub enum SupervisedResumeOutcome<Yields: Sized, Complete: Sized>
+{
+	/// Child has returned an intermediate result and would like to resume.
+	WouldLikeToResume(Yields),
+	
+	/// Child has exhausted its `PreemptionBudget` and would like to resume; it should be requeued.
+	BudgetExhausted,
+	
+	/// Child has completed successfully and will not be restarted.
+	Complete(Complete),
+	
+	/// Child has finished and how it finished has been passed to its `JoinHandle`; it will not be restarted.
+	Joined,
+	
+	/// Child failed and has been restarted, along with any others required by the `RestartStrategy`.
+	Restarted(ChildFailure<Complete>),
+	
+	/// The handle was not for a live, supervised child.
+	NotSupervised,
+}
@@ -0,0 +1,275 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// An Erlang-style supervisor of a fixed set of children of one coroutine manager.
+///
+/// Children that panic, or complete with a value that `SupervisorChildFactory::is_failure()` considers to be an error, are restarted according to a `RestartStrategy` with fresh start arguments from a `SupervisorChildFactory`.
+/// If restarts exceed the `RestartIntensity`, the supervisor gives up and cancels all of its children.
+///
+/// Use `resume()` rather than `CoroutineManager::resume_coroutine()` for supervised children.
+#[derive(Debug)]
+pub struct Supervisor<C: Coroutine, CoroutineInformation: 'static + Sized, F: SupervisorChildFactory<C, CoroutineInformation>>
+{
+	factory: F,
+	restart_strategy: RestartStrategy,
+	restart_intensity: RestartIntensity,
+	restarts: VecDeque<Instant>,
+	children: Vec<Option<CoroutineInstanceHandle>>,
+	marker: PhantomData<(C, CoroutineInformation)>,
+}
+
+impl<C: Coroutine, CoroutineInformation: 'static + Sized, F: SupervisorChildFactory<C, CoroutineInformation>> Supervisor<C, CoroutineInformation, F>
+{
+	/// New instance; children are not started until `start()` is called.
+	#[inline(always)]
+	pub fn new(factory: F, number_of_children: usize, restart_strategy: RestartStrategy, restart_intensity: RestartIntensity) -> Self
+	{
+		Self
+		{
+			factory,
+			restart_strategy,
+			restart_intensity,
+			restarts: VecDeque::with_capacity(restart_intensity.maximum_restarts + 1),
+			children: vec![None; number_of_children],
+			marker: PhantomData,
+		}
+	}
+	
+	/// Index of the child with `coroutine_instance_handle`.
+	#[inline(always)]
+	pub fn child_index(&self, coroutine_instance_handle: CoroutineInstanceHandle) -> Option<usize>
+	{
+		self.children.iter().position(|child| *child == Some(coroutine_instance_handle))
+	}
+	
+	/// Handles of children which are running (ie have started and yielded).
+	#[inline(always)]
+	pub fn running_children(&self) -> impl Iterator<Item=(usize, CoroutineInstanceHandle)> + '_
+	{
+		self.children.iter().enumerate().filter_map(|(child_index, child)| child.map(|coroutine_instance_handle| (child_index, coroutine_instance_handle)))
+	}
+	
+	/// Starts all children in order.
+	///
+	/// `started` is called for each child that yields after starting, with `None` if it exhausted its `PreemptionBudget` (and should be requeued).
+	#[inline(always)]
+	pub fn start<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, mut started: impl FnMut(usize, CoroutineInstanceHandle, Option<C::Yields>)) -> Result<(), SupervisorError>
+	{
+		let mut to_start = vec![true; self.children.len()];
+		self.start_children(coroutine_manager, &mut to_start, &mut started)
+	}
+	
+	/// Resumes a supervised child, restarting it (and others) if it fails.
+	///
+	/// `started` is called for each restarted child that yields after starting, as for `start()`.
+	#[inline(always)]
+	pub fn resume<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, coroutine_instance_handle: CoroutineInstanceHandle, resume_arguments: C::ResumeArguments, mut started: impl FnMut(usize, CoroutineInstanceHandle, Option<C::Yields>)) -> Result<SupervisedResumeOutcome<C::Yields, C::Complete>, SupervisorError>
+	{
+		use self::SupervisedResumeOutcome::*;
+		
+		let child_index = match self.child_index(coroutine_instance_handle)
+		{
+			None => return Ok(NotSupervised),
+			
+			Some(child_index) => child_index,
+		};
+		
+		let coroutine_instance_pointer = match coroutine_manager.live_coroutine_instance_pointer(coroutine_instance_handle)
+		{
+			None =>
+			{
+				self.children[child_index] = None;
+				return Ok(NotSupervised)
+			}
+			
+			Some(coroutine_instance_pointer) => coroutine_instance_pointer,
+		};
+		
+		match catch_unwind(AssertUnwindSafe(|| coroutine_manager.resume_coroutine(coroutine_instance_pointer, resume_arguments)))
+		{
+			Ok(ResumeOutcome::WouldLikeToResume(yields)) => Ok(WouldLikeToResume(yields)),
+			
+			Ok(ResumeOutcome::BudgetExhausted) => Ok(BudgetExhausted),
+			
+			Ok(ResumeOutcome::Complete(complete)) =>
+			{
+				self.children[child_index] = None;
+				if self.factory.is_failure(child_index, &complete)
+				{
+					self.restart_after_failure(coroutine_manager, child_index, &mut started)?;
+					Ok(Restarted(ChildFailure::Error(complete)))
+				}
+				else
+				{
+					Ok(Complete(complete))
+				}
+			}
+			
+			Ok(ResumeOutcome::Joined) =>
+			{
+				self.children[child_index] = None;
+				Ok(Joined)
+			}
+			
+			Err(panic_information) =>
+			{
+				self.children[child_index] = None;
+				self.restart_after_failure(coroutine_manager, child_index, &mut started)?;
+				Ok(Restarted(ChildFailure::Panicked(panic_information)))
+			}
+		}
+	}
+	
+	/// Cancels all running children.
+	#[inline(always)]
+	pub fn terminate<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>)
+	{
+		for child_index in 0 .. self.children.len()
+		{
+			Self::cancel_child(coroutine_manager, &mut self.children[child_index])
+		}
+	}
+	
+	#[inline(always)]
+	fn restart_after_failure<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, failed_child_index: usize, started: &mut impl FnMut(usize, CoroutineInstanceHandle, Option<C::Yields>)) -> Result<(), SupervisorError>
+	{
+		let mut to_start = vec![false; self.children.len()];
+		self.child_failed(coroutine_manager, failed_child_index, &mut to_start)?;
+		self.start_children(coroutine_manager, &mut to_start, started)
+	}
+	
+	/// Starts every child marked in `to_start`, lowest index first.
+	///
+	/// A child which fails whilst starting marks children to restart rather than starting them itself, so each child is started at most once for each time it is marked.
+	#[inline(always)]
+	fn start_children<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, to_start: &mut [bool], started: &mut impl FnMut(usize, CoroutineInstanceHandle, Option<C::Yields>)) -> Result<(), SupervisorError>
+	{
+		while let Some(child_index) = to_start.iter().position(|&marked| marked)
+		{
+			to_start[child_index] = false;
+			
+			match self.start_child(coroutine_manager, child_index, started)
+			{
+				Ok(false) => (),
+				
+				Ok(true) => self.child_failed(coroutine_manager, child_index, to_start)?,
+				
+				Err(supervisor_error) =>
+				{
+					self.terminate(coroutine_manager);
+					return Err(supervisor_error)
+				}
+			}
+		}
+		
+		Ok(())
+	}
+	
+	/// Cancels the children to restart and marks them in `to_start`.
+	#[inline(always)]
+	fn child_failed<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, failed_child_index: usize, to_start: &mut [bool]) -> Result<(), SupervisorError>
+	{
+		if self.restart_intensity_exceeded(Instant::now())
+		{
+			self.terminate(coroutine_manager);
+			return Err(SupervisorError::RestartIntensityExceeded)
+		}
+		
+		use self::RestartStrategy::*;
+		
+		let to_restart = match self.restart_strategy
+		{
+			OneForOne => failed_child_index .. (failed_child_index + 1),
+			
+			OneForAll => 0 .. self.children.len(),
+			
+			RestForOne => failed_child_index .. self.children.len(),
+		};
+		
+		for child_index in to_restart
+		{
+			Self::cancel_child(coroutine_manager, &mut self.children[child_index]);
+			to_start[child_index] = true;
+		}
+		
+		Ok(())
+	}
+	
+	/// Returns `Ok(true)` if the child failed whilst starting.
+	#[inline(always)]
+	fn start_child<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(&mut self, coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, child_index: usize, started: &mut impl FnMut(usize, CoroutineInstanceHandle, Option<C::Yields>)) -> Result<bool, SupervisorError>
+	{
+		let (coroutine_information, start_arguments) = self.factory.create(child_index);
+		
+		match catch_unwind(AssertUnwindSafe(|| coroutine_manager.start_coroutine_returning_handle(coroutine_information, start_arguments)))
+		{
+			Ok(Err(AllocError)) => Err(SupervisorError::CouldNotStartChild),
+			
+			Ok(Ok((coroutine_instance_handle, StartOutcome::WouldLikeToResume(yields)))) =>
+			{
+				self.children[child_index] = Some(coroutine_instance_handle);
+				started(child_index, coroutine_instance_handle, Some(yields));
+				Ok(false)
+			}
+			
+			Ok(Ok((coroutine_instance_handle, StartOutcome::BudgetExhausted))) =>
+			{
+				self.children[child_index] = Some(coroutine_instance_handle);
+				started(child_index, coroutine_instance_handle, None);
+				Ok(false)
+			}
+			
+			Ok(Ok((_, StartOutcome::Complete(complete)))) =>
+			{
+				self.children[child_index] = None;
+				Ok(self.factory.is_failure(child_index, &complete))
+			}
+			
+			// How the child finished was passed to its `JoinHandle`, so whether it failed is not known here; it is treated as having finished.
+			Ok(Ok((_, StartOutcome::Joined))) =>
+			{
+				self.children[child_index] = None;
+				Ok(false)
+			}
+			
+			Err(_panic_information) =>
+			{
+				self.children[child_index] = None;
+				Ok(true)
+			}
+		}
+	}
+	
+	/// A panic whilst killing a child is ignored; the child is gone either way.
+	#[inline(always)]
+	fn cancel_child<CoroutineHeapSize: MemorySize, StackSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>, LifecycleHooks: CoroutineLifecycleHooks<C, CoroutineInformation>>(coroutine_manager: &mut CoroutineManager<CoroutineHeapSize, StackSize, GTACSA, C, CoroutineInformation, LifecycleHooks>, child: &mut Option<CoroutineInstanceHandle>)
+	{
+		if let Some(coroutine_instance_handle) = child.take()
+		{
+			if let Some(coroutine_instance_pointer) = coroutine_manager.live_coroutine_instance_pointer(coroutine_instance_handle)
+			{
+				let _ = catch_unwind(AssertUnwindSafe(|| coroutine_manager.cancel_coroutine(coroutine_instance_pointer)));
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn restart_intensity_exceeded(&mut self, now: Instant) -> bool
+	{
+		while let Some(&oldest) = self.restarts.front()
+		{
+			if now.saturating_duration_since(oldest) > self.restart_intensity.period
+			{
+				self.restarts.pop_front();
+			}
+			else
+			{
+				break
+			}
+		}
+		
+		self.restarts.push_back(now);
+		self.restarts.len() > self.restart_intensity.maximum_restarts
+	}
+}
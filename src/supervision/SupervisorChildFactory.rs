@@ -0,0 +1,19 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Creates the coroutine information and start arguments for supervised children, both when first started and when restarted.
+pub trait SupervisorChildFactory<C: Coroutine, CoroutineInformation: Sized>
+{
+	/// Fresh coroutine information and start arguments for the child at `child_index`.
+	fn create(&mut self, child_index: usize) -> (CoroutineInformation, C::StartArguments);
+	
+	/// Does `complete` represent an error, so that the child should be restarted?
+	///
+	/// By default, only panics cause a restart.
+	#[inline(always)]
+	fn is_failure(&self, _child_index: usize, _complete: &C::Complete) -> bool
+	{
+		false
+	}
+}
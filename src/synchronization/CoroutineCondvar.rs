@@ -0,0 +1,69 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A condition variable for coroutines on one thread, used with a `CoroutineMutex`.
+///
+/// Waiting suspends the coroutine rather than blocking the thread.
+/// As with all condition variables, spurious wake ups can occur; always check the condition again.
+#[derive(Debug)]
+pub struct CoroutineCondvar<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	waiting: RefCell<WaitQueue>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineCondvar<CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					waiting: RefCell::new(WaitQueue::default()),
+				}
+			)
+		})
+	}
+	
+	/// Unlocks `guard`, suspends the coroutine of `yielder` until notified and then locks again.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	///
+	/// If killed, the lock is not re-acquired.
+	#[inline(always)]
+	pub fn wait<'a, T, ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, guard: CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>, E>
+	{
+		let mutex = guard.mutex();
+		drop(guard);
+		
+		if !yielder.wait(&self.run_queue, WaitingOn::Condvar(self as *const Self as usize), &self.waiting, |waiting| waiting, yields())
+		{
+			return Err(kill_error)
+		}
+		
+		mutex.lock(yielder, yields, kill_error)
+	}
+	
+	/// Wakes one waiting coroutine.
+	#[inline(always)]
+	pub fn notify_one(&self)
+	{
+		self.waiting.borrow_mut().wake_one(&self.run_queue);
+	}
+	
+	/// Wakes all waiting coroutines.
+	#[inline(always)]
+	pub fn notify_all(&self)
+	{
+		self.waiting.borrow_mut().wake_all(&self.run_queue)
+	}
+}
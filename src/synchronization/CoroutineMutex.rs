@@ -0,0 +1,99 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A mutual exclusion lock for coroutines on one thread.
+///
+/// Waiting for the lock suspends the coroutine rather than blocking the thread.
+/// The lock is not fair: a coroutine that has been woken may find that another coroutine has taken the lock first, in which case it waits again.
+pub struct CoroutineMutex<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	state: RefCell<CoroutineMutexState>,
+	value: UnsafeCell<T>,
+}
+
+impl<T: Debug, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for CoroutineMutex<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutineMutex({:?})", self.state.borrow())
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineMutex<T, CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, value: T) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					state: RefCell::new(CoroutineMutexState::default()),
+					value: UnsafeCell::new(value),
+				}
+			)
+		})
+	}
+	
+	/// Locks, suspending the coroutine of `yielder` whilst the lock is held by another coroutine.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn lock<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<CoroutineMutexGuard<T, CoroutineHeapSize, GTACSA>, E>
+	{
+		loop
+		{
+			if let Some(guard) = self.try_lock()
+			{
+				return Ok(guard)
+			}
+			
+			if !yielder.wait(&self.run_queue, WaitingOn::Mutex(self.resource()), &self.state, |state| &mut state.waiting, yields())
+			{
+				return Err(kill_error)
+			}
+		}
+	}
+	
+	/// Locks without suspending.
+	#[inline(always)]
+	pub fn try_lock(&self) -> Option<CoroutineMutexGuard<T, CoroutineHeapSize, GTACSA>>
+	{
+		let mut state = self.state.borrow_mut();
+		if state.locked
+		{
+			None
+		}
+		else
+		{
+			state.locked = true;
+			let holder = current_coroutine_instance_handle();
+			self.run_queue.acquired(self.resource(), holder);
+			Some(CoroutineMutexGuard(self, holder))
+		}
+	}
+	
+	#[inline(always)]
+	fn resource(&self) -> usize
+	{
+		self as *const Self as usize
+	}
+	
+	#[inline(always)]
+	fn unlock(&self, holder: Option<CoroutineInstanceHandle>)
+	{
+		self.run_queue.released(self.resource(), holder);
+		let mut state = self.state.borrow_mut();
+		state.locked = false;
+		state.waiting.wake_one(&self.run_queue);
+	}
+}
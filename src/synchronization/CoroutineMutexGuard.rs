@@ -0,0 +1,53 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Unlocks a `CoroutineMutex` when dropped.
+pub struct CoroutineMutexGuard<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&'a CoroutineMutex<T, CoroutineHeapSize, GTACSA>, Option<CoroutineInstanceHandle>);
+
+impl<'a, T: Debug, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutineMutexGuard({:?})", self.deref())
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.unlock(self.1)
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Deref for CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	type Target = T;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		unsafe { & * self.0.value.get() }
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> DerefMut for CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		unsafe { &mut * self.0.value.get() }
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineMutexGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn mutex(&self) -> &'a CoroutineMutex<T, CoroutineHeapSize, GTACSA>
+	{
+		self.0
+	}
+}
@@ -0,0 +1,149 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A reader-writer lock for coroutines on one thread.
+///
+/// Waiting for the lock suspends the coroutine rather than blocking the thread.
+/// Waiting writers are preferred over new readers.
+pub struct CoroutineRwLock<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	state: RefCell<CoroutineRwLockState>,
+	value: UnsafeCell<T>,
+}
+
+impl<T: Debug, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for CoroutineRwLock<T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutineRwLock({:?})", self.state.borrow())
+	}
+}
+
+impl<T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineRwLock<T, CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, value: T) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					state: RefCell::new(CoroutineRwLockState::default()),
+					value: UnsafeCell::new(value),
+				}
+			)
+		})
+	}
+	
+	/// Locks for reading, suspending the coroutine of `yielder` whilst the lock is held (or waited for) by a writer.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn read<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<CoroutineRwLockReadGuard<T, CoroutineHeapSize, GTACSA>, E>
+	{
+		loop
+		{
+			if let Some(guard) = self.try_read()
+			{
+				return Ok(guard)
+			}
+			
+			if !yielder.wait_passing_on_wake(&self.run_queue, WaitingOn::RwLockRead(self.resource()), &self.state, |state| &mut state.waiting_readers, |state| state.wake_next(&self.run_queue), yields())
+			{
+				return Err(kill_error)
+			}
+		}
+	}
+	
+	/// Locks for writing, suspending the coroutine of `yielder` whilst the lock is held by readers or a writer.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn write<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<CoroutineRwLockWriteGuard<T, CoroutineHeapSize, GTACSA>, E>
+	{
+		loop
+		{
+			if let Some(guard) = self.try_write()
+			{
+				return Ok(guard)
+			}
+			
+			if !yielder.wait_passing_on_wake(&self.run_queue, WaitingOn::RwLockWrite(self.resource()), &self.state, |state| &mut state.waiting_writers, |state| state.wake_next(&self.run_queue), yields())
+			{
+				return Err(kill_error)
+			}
+		}
+	}
+	
+	/// Locks for reading without suspending.
+	#[inline(always)]
+	pub fn try_read(&self) -> Option<CoroutineRwLockReadGuard<T, CoroutineHeapSize, GTACSA>>
+	{
+		let mut state = self.state.borrow_mut();
+		if state.can_read()
+		{
+			state.readers += 1;
+			let holder = current_coroutine_instance_handle();
+			self.run_queue.acquired(self.resource(), holder);
+			Some(CoroutineRwLockReadGuard(self, holder))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// Locks for writing without suspending.
+	#[inline(always)]
+	pub fn try_write(&self) -> Option<CoroutineRwLockWriteGuard<T, CoroutineHeapSize, GTACSA>>
+	{
+		let mut state = self.state.borrow_mut();
+		if state.can_write()
+		{
+			state.writer = true;
+			let holder = current_coroutine_instance_handle();
+			self.run_queue.acquired(self.resource(), holder);
+			Some(CoroutineRwLockWriteGuard(self, holder))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	#[inline(always)]
+	fn resource(&self) -> usize
+	{
+		self as *const Self as usize
+	}
+	
+	#[inline(always)]
+	fn read_unlock(&self, holder: Option<CoroutineInstanceHandle>)
+	{
+		self.run_queue.released(self.resource(), holder);
+		let mut state = self.state.borrow_mut();
+		state.readers -= 1;
+		if state.readers == 0
+		{
+			state.wake_next(&self.run_queue)
+		}
+	}
+	
+	#[inline(always)]
+	fn write_unlock(&self, holder: Option<CoroutineInstanceHandle>)
+	{
+		self.run_queue.released(self.resource(), holder);
+		let mut state = self.state.borrow_mut();
+		state.writer = false;
+		state.wake_next(&self.run_queue)
+	}
+}
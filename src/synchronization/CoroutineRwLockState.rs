@@ -0,0 +1,37 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[derive(Debug, Default)]
+struct CoroutineRwLockState
+{
+	readers: usize,
+	writer: bool,
+	waiting_readers: WaitQueue,
+	waiting_writers: WaitQueue,
+}
+
+impl CoroutineRwLockState
+{
+	/// Writers are preferred so that they are not starved by a continuous stream of readers.
+	#[inline(always)]
+	fn can_read(&self) -> bool
+	{
+		!self.writer && self.waiting_writers.is_empty()
+	}
+	
+	#[inline(always)]
+	fn can_write(&self) -> bool
+	{
+		!self.writer && self.readers == 0
+	}
+	
+	#[inline(always)]
+	fn wake_next<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&mut self, run_queue: &RunQueue<CoroutineHeapSize, GTACSA>)
+	{
+		if !self.waiting_writers.wake_one(run_queue)
+		{
+			self.waiting_readers.wake_all(run_queue)
+		}
+	}
+}
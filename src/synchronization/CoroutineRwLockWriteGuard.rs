@@ -0,0 +1,44 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Unlocks a `CoroutineRwLock` locked for writing when dropped.
+pub struct CoroutineRwLockWriteGuard<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&'a CoroutineRwLock<T, CoroutineHeapSize, GTACSA>, Option<CoroutineInstanceHandle>);
+
+impl<'a, T: Debug, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Debug for CoroutineRwLockWriteGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "CoroutineRwLockWriteGuard({:?})", self.deref())
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for CoroutineRwLockWriteGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.write_unlock(self.1)
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Deref for CoroutineRwLockWriteGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	type Target = T;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		unsafe { & * self.0.value.get() }
+	}
+}
+
+impl<'a, T, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> DerefMut for CoroutineRwLockWriteGuard<'a, T, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		unsafe { &mut * self.0.value.get() }
+	}
+}
@@ -0,0 +1,115 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A counting semaphore for coroutines on one thread.
+///
+/// Waiting for a permit suspends the coroutine rather than blocking the thread.
+#[derive(Debug)]
+pub struct CoroutineSemaphore<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	state: RefCell<CoroutineSemaphoreState>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineSemaphore<CoroutineHeapSize, GTACSA>
+{
+	/// New instance.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>, permits: usize) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					state: RefCell::new
+					(
+						CoroutineSemaphoreState
+						{
+							permits,
+							waiting: WaitQueue::default(),
+						}
+					),
+				}
+			)
+		})
+	}
+	
+	/// Number of permits available.
+	#[inline(always)]
+	pub fn available_permits(&self) -> usize
+	{
+		self.state.borrow().permits
+	}
+	
+	/// Acquires a permit, suspending the coroutine of `yielder` whilst none are available.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn acquire<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<CoroutineSemaphorePermit<CoroutineHeapSize, GTACSA>, E>
+	{
+		loop
+		{
+			if let Some(permit) = self.try_acquire()
+			{
+				return Ok(permit)
+			}
+			
+			if !yielder.wait(&self.run_queue, WaitingOn::Semaphore(self.resource()), &self.state, |state| &mut state.waiting, yields())
+			{
+				return Err(kill_error)
+			}
+		}
+	}
+	
+	/// Acquires a permit without suspending.
+	#[inline(always)]
+	pub fn try_acquire(&self) -> Option<CoroutineSemaphorePermit<CoroutineHeapSize, GTACSA>>
+	{
+		let mut state = self.state.borrow_mut();
+		if state.permits == 0
+		{
+			None
+		}
+		else
+		{
+			state.permits -= 1;
+			let holder = current_coroutine_instance_handle();
+			self.run_queue.acquired(self.resource(), holder);
+			Some(CoroutineSemaphorePermit(self, holder))
+		}
+	}
+	
+	#[inline(always)]
+	fn resource(&self) -> usize
+	{
+		self as *const Self as usize
+	}
+	
+	#[inline(always)]
+	fn release(&self, holder: Option<CoroutineInstanceHandle>)
+	{
+		self.run_queue.released(self.resource(), holder);
+		self.add_permits(1)
+	}
+	
+	/// Adds permits, waking as many waiting coroutines.
+	#[inline(always)]
+	pub fn add_permits(&self, additional_permits: usize)
+	{
+		let mut state = self.state.borrow_mut();
+		state.permits += additional_permits;
+		for _ in 0 .. additional_permits
+		{
+			if !state.waiting.wake_one(&self.run_queue)
+			{
+				break
+			}
+		}
+	}
+}
@@ -0,0 +1,27 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Releases a permit of a `CoroutineSemaphore` when dropped.
+#[derive(Debug)]
+pub struct CoroutineSemaphorePermit<'a, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&'a CoroutineSemaphore<CoroutineHeapSize, GTACSA>, Option<CoroutineInstanceHandle>);
+
+impl<'a, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for CoroutineSemaphorePermit<'a, CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.0.release(self.1)
+	}
+}
+
+impl<'a, CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> CoroutineSemaphorePermit<'a, CoroutineHeapSize, GTACSA>
+{
+	/// Keeps the permit acquired, ie does not return it to the semaphore.
+	#[inline(always)]
+	pub fn forget(self)
+	{
+		self.0.run_queue.released(self.0.resource(), self.1);
+		forget(self)
+	}
+}
@@ -0,0 +1,73 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Waits for a number of tasks, typically performed by other coroutines on the same thread, to be done.
+///
+/// Waiting suspends the coroutine rather than blocking the thread.
+#[derive(Debug)]
+pub struct WaitGroup<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	run_queue: Rc<RunQueue<CoroutineHeapSize, GTACSA>>,
+	state: RefCell<WaitGroupState>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> WaitGroup<CoroutineHeapSize, GTACSA>
+{
+	/// New instance with a count of zero.
+	///
+	/// Memory is allocated using the thread-local allocator.
+	#[inline(always)]
+	pub fn new(run_queue: &Rc<RunQueue<CoroutineHeapSize, GTACSA>>) -> Rc<Self>
+	{
+		run_queue.outside_coroutine_local_heap(||
+		{
+			Rc::new
+			(
+				Self
+				{
+					run_queue: run_queue.clone(),
+					state: RefCell::new(WaitGroupState::default()),
+				}
+			)
+		})
+	}
+	
+	/// Adds to the count of tasks that are not yet done.
+	#[inline(always)]
+	pub fn add(&self, count: usize)
+	{
+		self.state.borrow_mut().count += count
+	}
+	
+	/// Marks a task as done; when the count reaches zero, all waiting coroutines are woken.
+	///
+	/// Panics if the count is already zero.
+	#[inline(always)]
+	pub fn done(&self)
+	{
+		let mut state = self.state.borrow_mut();
+		assert_ne!(state.count, 0, "done() called more times than add()");
+		state.count -= 1;
+		if state.count == 0
+		{
+			state.waiting.wake_all(&self.run_queue)
+		}
+	}
+	
+	/// Suspends the coroutine of `yielder` until the count is zero.
+	///
+	/// `yields` is called to produce a value every time the coroutine suspends; resume arguments received whilst suspended are discarded.
+	#[inline(always)]
+	pub fn wait<ResumeArguments, Yields, Complete, E>(&self, yielder: &mut Yielder<ResumeArguments, Yields, Complete>, mut yields: impl FnMut() -> Yields, kill_error: E) -> Result<(), E>
+	{
+		while self.state.borrow().count != 0
+		{
+			if !yielder.wait(&self.run_queue, WaitingOn::WaitGroup(self as *const Self as usize), &self.state, |state| &mut state.waiting, yields())
+			{
+				return Err(kill_error)
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,25 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+
+
+use std::cell::UnsafeCell;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+
+include!("CoroutineCondvar.rs");
+include!("CoroutineMutex.rs");
+include!("CoroutineMutexGuard.rs");
+include!("CoroutineMutexState.rs");
+include!("CoroutineRwLock.rs");
+include!("CoroutineRwLockReadGuard.rs");
+include!("CoroutineRwLockState.rs");
+include!("CoroutineRwLockWriteGuard.rs");
+include!("CoroutineSemaphore.rs");
+include!("CoroutineSemaphorePermit.rs");
+include!("CoroutineSemaphoreState.rs");
+include!("WaitGroup.rs");
+include!("WaitGroupState.rs");
@@ -0,0 +1,144 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+
+
+#[test]
+fn coroutine_instance_handle_packs_every_field_into_its_own_bits()
+{
+	let coroutine_instances = [0u64; 32];
+	let base_pointer = NonNull::from(&coroutine_instances[0]);
+	let pointer = NonNull::from(&coroutine_instances[21]);
+	
+	let coroutine_instance_handle = CoroutineInstanceHandle::new(true, CoroutineManagerIndex(0xA5), UserBits(0b1001), CoroutineGenerationCounter(0x00AB_CDEF), pointer, base_pointer);
+	
+	assert!(coroutine_instance_handle.is_coroutine());
+	assert!(!CoroutineInstanceHandle::is_not_for_a_coroutine(coroutine_instance_handle.unwrap()));
+	assert_eq!(coroutine_instance_handle.coroutine_manager_index(), CoroutineManagerIndex(0xA5));
+	assert_eq!(coroutine_instance_handle.user_bits(), UserBits(0b1001));
+	assert_eq!(coroutine_instance_handle.generation(), CoroutineGenerationCounter(0x00AB_CDEF));
+	assert_eq!(coroutine_instance_handle.index(), 21);
+	assert_eq!(coroutine_instance_handle.into_absolute_pointer(base_pointer), pointer);
+	assert_eq!(coroutine_instance_handle.unwrap(), 1 << 63 | 0xA5 << 52 | 0b1001 << 48 | 0x00AB_CDEF << 24 | 21);
+}
+
+#[test]
+fn coroutine_instance_handle_set_user_bits_changes_only_user_bits()
+{
+	let coroutine_instances = [0u64; 4];
+	let base_pointer = NonNull::from(&coroutine_instances[0]);
+	let pointer = NonNull::from(&coroutine_instances[3]);
+	
+	let coroutine_instance_handle = CoroutineInstanceHandle::new(true, CoroutineManagerIndex(7), UserBits::Zero, CoroutineGenerationCounter(9), pointer, base_pointer);
+	let with_user_bits = coroutine_instance_handle.set_user_bits(UserBits(0b1111));
+	
+	assert_eq!(with_user_bits.user_bits(), UserBits(0b1111));
+	assert_eq!(with_user_bits.set_user_bits(UserBits::Zero), coroutine_instance_handle);
+	assert_eq!(with_user_bits.coroutine_manager_index(), CoroutineManagerIndex(7));
+	assert_eq!(with_user_bits.generation(), CoroutineGenerationCounter(9));
+	assert_eq!(with_user_bits.index(), 3);
+}
+
+#[test]
+fn coroutine_instance_handle_is_not_for_a_coroutine_if_top_bit_is_clear()
+{
+	assert!(CoroutineInstanceHandle::is_not_for_a_coroutine(0x7FFF_FFFF_FFFF_FFFF));
+	assert!(!CoroutineInstanceHandle::wrap(0x7FFF_FFFF_FFFF_FFFF).is_coroutine());
+}
+
+#[test]
+fn coroutine_generation_counter_rejects_crafted_handles_for_coroutine_instances_that_are_not_live()
+{
+	let never_allocated = CoroutineInstanceHandle::wrap(1 << 63 | 5);
+	assert_eq!(never_allocated.generation(), CoroutineGenerationCounter::default());
+	assert!(!CoroutineGenerationCounter::default().is_current(false, never_allocated.generation()));
+	
+	let mut generation = CoroutineGenerationCounter::default();
+	generation.increment();
+	let live = CoroutineInstanceHandle::wrap(1 << 63 | 1 << 24 | 5);
+	assert!(generation.is_current(true, live.generation()));
+	
+	generation.increment();
+	assert!(!generation.is_current(true, live.generation()));
+	
+	let freed = CoroutineInstanceHandle::wrap(1 << 63 | 2 << 24 | 5);
+	assert!(generation.is_current(true, freed.generation()));
+	assert!(!generation.is_current(false, freed.generation()));
+}
+
+#[test]
+fn write_prometheus_text_labels_each_snapshot()
+{
+	let metrics = CoroutineManagerMetrics
+	{
+		started: 5,
+		completed: 3,
+		panicked: 1,
+		cancelled: 0,
+		live: 1,
+		peak_live: 4,
+		allocation_failures: 2,
+		resumes: 17,
+		on_cpu_cycles: 12345,
+	};
+	let snapshots =
+	[
+		CoroutineManagerMetricsSnapshot { coroutine_manager_index: CoroutineManagerIndex(0), capacity: 64, metrics },
+		CoroutineManagerMetricsSnapshot { coroutine_manager_index: CoroutineManagerIndex(3), capacity: 128, metrics: CoroutineManagerMetrics::default() },
+	];
+	
+	let mut text = Vec::new();
+	CoroutineManagerMetricsSnapshot::write_prometheus_text(&snapshots, &mut text).unwrap();
+	let text = String::from_utf8(text).unwrap();
+	
+	assert!(text.starts_with("# HELP context_coroutine_capacity Coroutine instances that can be live at once.\n# TYPE context_coroutine_capacity gauge\ncontext_coroutine_capacity{coroutine_manager_index=\"0\"} 64\ncontext_coroutine_capacity{coroutine_manager_index=\"3\"} 128\n"));
+	assert!(text.contains("# TYPE context_coroutine_started_total counter\ncontext_coroutine_started_total{coroutine_manager_index=\"0\"} 5\ncontext_coroutine_started_total{coroutine_manager_index=\"3\"} 0\n"));
+	assert!(text.contains("context_coroutine_on_cpu_cycles_total{coroutine_manager_index=\"0\"} 12345\n"));
+	assert!(text.ends_with("context_coroutine_on_cpu_cycles_total{coroutine_manager_index=\"3\"} 0\n"));
+	assert_eq!(text.lines().filter(|line| line.starts_with("# TYPE ")).count(), 10);
+	assert_eq!(text.lines().filter(|line| !line.starts_with('#')).count(), 20);
+}
+
+#[test]
+fn write_folded_writes_outermost_frame_first_and_count()
+{
+	let mut parked_stack_profile = ParkedStackProfile::default();
+	parked_stack_profile.record(vec![0x10, 0x20, 0x30]);
+	parked_stack_profile.record(vec![0x10, 0x20, 0x30]);
+	parked_stack_profile.record(vec![0x40]);
+	
+	let mut folded = Vec::new();
+	parked_stack_profile.write_folded(&mut folded).unwrap();
+	let folded = String::from_utf8(folded).unwrap();
+	
+	let mut lines = folded.lines().collect::<Vec<_>>();
+	lines.sort_unstable();
+	assert_eq!(lines, vec!["0x30;0x20;0x10 2", "0x40 1"]);
+}
+
+#[test]
+fn wait_for_graph_ignores_a_coroutine_waiting_on_a_channel_endpoint_it_last_used()
+{
+	let a = CoroutineInstanceHandle::wrap(1 << 63 | 1);
+	let b = CoroutineInstanceHandle::wrap(1 << 63 | 2);
+	const ChannelResource: usize = 0x1000;
+	const MutexResource: usize = 0x2000;
+	
+	let mut wait_for_graph = WaitForGraph::default();
+	wait_for_graph.acquired(ChannelResource, a);
+	wait_for_graph.started_waiting(a, WaitingOn::ChannelReceive(ChannelResource));
+	assert_eq!(wait_for_graph.find_cycle(), None);
+	
+	wait_for_graph.released(ChannelResource, a);
+	wait_for_graph.acquired(ChannelResource, b);
+	wait_for_graph.acquired(MutexResource, a);
+	wait_for_graph.started_waiting(b, WaitingOn::Mutex(MutexResource));
+	assert_eq!(wait_for_graph.find_cycle().map(|deadlock| deadlock.cycle().len()), Some(2));
+	
+	wait_for_graph.stopped_waiting(b);
+	wait_for_graph.stopped_waiting(a);
+	wait_for_graph.started_waiting(a, WaitingOn::Mutex(MutexResource));
+	assert_eq!(wait_for_graph.find_cycle(), Some(Deadlock(vec![(a, WaitingOn::Mutex(MutexResource))])));
+}
@@ -0,0 +1,51 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+#[derive(Debug)]
+struct TimerEntry
+{
+	// Incremented every time the entry is freed, so that stale `TimerKey`s are ignored.
+	generation: u32,
+	
+	// Either the next entry in a slot or the next free entry.
+	next: u32,
+	previous: u32,
+	
+	// `TimerEntry::Free` if not scheduled.
+	level: u8,
+	slot: u8,
+	
+	when: u64,
+	coroutine_instance_handle: CoroutineInstanceHandle,
+	purpose: TimerPurpose,
+}
+
+impl TimerEntry
+{
+	const None: u32 = u32::MAX;
+	
+	const Free: u8 = u8::MAX;
+	
+	#[inline(always)]
+	fn free(next: u32) -> Self
+	{
+		Self
+		{
+			generation: 0,
+			next,
+			previous: Self::None,
+			level: Self::Free,
+			slot: 0,
+			when: 0,
+			coroutine_instance_handle: CoroutineInstanceHandle::default(),
+			purpose: TimerPurpose::Wake,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_free(&self) -> bool
+	{
+		self.level == Self::Free
+	}
+}
@@ -0,0 +1,16 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Why a timer was scheduled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimerPurpose
+{
+	/// The coroutine should be resumed when the timer expires.
+	///
+	/// Scheduled by `Yielder::sleep_until()` and `Yielder::yields_with_deadline()`.
+	Wake,
+	
+	/// The coroutine has run for too long in total and should be cancelled when the timer expires.
+	LifetimeDeadline,
+}
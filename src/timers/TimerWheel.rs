@@ -0,0 +1,392 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A hierarchical timer wheel of coroutines to resume or to cancel.
+///
+/// There are 6 levels of 64 slots; a slot at level `n` covers `64^n` ticks, so timers up to `64^6` ticks (about 2 years with a 1ms tick) in the future can be scheduled.
+/// Timers further in the future are clamped.
+///
+/// All memory is allocated in `new()`, so timers can be scheduled and cancelled from inside a coroutine without using the coroutine-local heap.
+/// To share a timer wheel with coroutines, wrap it in a `RefCell` (and `Rc`) and pass it in `Coroutine::StartArguments`.
+///
+/// A timer wheel can be driven either by passing `timeout()` to the reactor's wait (eg `epoll_wait()` or `io_uring_enter()`) or by arming a `timerfd` with `next_expiry()`, and then calling `expire()`.
+///
+/// Timers are not removed when a coroutine is cancelled; instead, pass expired handles to `CoroutineManager::expired_timer()`, which discards those with a stale generation.
+#[derive(Debug)]
+pub struct TimerWheel
+{
+	start: Instant,
+	tick_in_nanoseconds: u64,
+	
+	// Ticks since `start` that have been processed.
+	elapsed: u64,
+	
+	entries: Box<[TimerEntry]>,
+	free_head: u32,
+	number_scheduled: usize,
+	
+	slot_heads: [[u32; TimerWheel::SlotsPerLevel]; TimerWheel::NumberOfLevels],
+	occupied: [u64; TimerWheel::NumberOfLevels],
+}
+
+impl TimerWheel
+{
+	const NumberOfLevels: usize = 6;
+	
+	const SlotsPerLevelBits: u32 = 6;
+	
+	const SlotsPerLevel: usize = 1 << Self::SlotsPerLevelBits;
+	
+	const SlotMask: u64 = (Self::SlotsPerLevel as u64) - 1;
+	
+	const MaximumTicks: u64 = 1 << (Self::SlotsPerLevelBits as u64 * Self::NumberOfLevels as u64);
+	
+	/// New instance.
+	///
+	/// `tick` is the granularity of the wheel; deadlines are rounded up to a whole tick.
+	///
+	/// `capacity` is the maximum number of timers that can be scheduled at once; a value of at least twice the `ideal_maximum_number_of_coroutines` of all coroutine managers using this wheel allows every coroutine to have both a `TimerPurpose::Wake` and a `TimerPurpose::LifetimeDeadline` timer.
+	#[inline(always)]
+	pub fn new(tick: Duration, capacity: NonZeroU32) -> Self
+	{
+		let tick_in_nanoseconds = tick.as_nanos();
+		assert!(tick_in_nanoseconds != 0, "tick can not be zero");
+		assert!(tick_in_nanoseconds <= (u64::MAX as u128), "tick is too large");
+		
+		let capacity = capacity.get();
+		assert!(capacity != TimerEntry::None, "capacity is too large");
+		let mut entries = Vec::with_capacity(capacity as usize);
+		for index in 0 .. capacity
+		{
+			let next = if index + 1 == capacity
+			{
+				TimerEntry::None
+			}
+			else
+			{
+				index + 1
+			};
+			entries.push(TimerEntry::free(next));
+		}
+		
+		Self
+		{
+			start: Instant::now(),
+			tick_in_nanoseconds: tick_in_nanoseconds as u64,
+			elapsed: 0,
+			entries: entries.into_boxed_slice(),
+			free_head: 0,
+			number_scheduled: 0,
+			slot_heads: [[TimerEntry::None; Self::SlotsPerLevel]; Self::NumberOfLevels],
+			occupied: [0; Self::NumberOfLevels],
+		}
+	}
+	
+	/// Are there no timers scheduled?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.number_scheduled == 0
+	}
+	
+	/// Number of timers scheduled.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.number_scheduled
+	}
+	
+	/// Schedules a timer for `coroutine_instance_handle` to expire at `deadline`.
+	///
+	/// A deadline in the past expires on the next call to `expire()`.
+	///
+	/// Returns `Err(AllocError)` if `capacity` timers are already scheduled.
+	#[inline(always)]
+	pub fn schedule(&mut self, coroutine_instance_handle: CoroutineInstanceHandle, deadline: Instant, purpose: TimerPurpose) -> Result<TimerKey, AllocError>
+	{
+		let index = self.free_head;
+		if unlikely!(index == TimerEntry::None)
+		{
+			return Err(AllocError)
+		}
+		
+		let when =
+		{
+			let when = self.ticks_rounded_up(deadline);
+			let earliest = self.elapsed + 1;
+			let latest = self.elapsed + Self::MaximumTicks - 1;
+			min(max(when, earliest), latest)
+		};
+		
+		let entry = self.entry_mut(index);
+		let next_free = entry.next;
+		entry.when = when;
+		entry.coroutine_instance_handle = coroutine_instance_handle;
+		entry.purpose = purpose;
+		let generation = entry.generation;
+		
+		self.free_head = next_free;
+		self.number_scheduled += 1;
+		self.insert(index);
+		
+		Ok
+		(
+			TimerKey
+			{
+				index,
+				generation,
+			}
+		)
+	}
+	
+	/// Cancels a timer.
+	///
+	/// Returns `false` if the timer has already expired or been cancelled, or if `timer_key` is out of range (eg it is from a larger timer wheel).
+	#[inline(always)]
+	pub fn cancel(&mut self, timer_key: TimerKey) -> bool
+	{
+		if !self.is_scheduled(timer_key)
+		{
+			return false
+		}
+		
+		let index = timer_key.index;
+		self.unlink(index);
+		self.release(index);
+		true
+	}
+	
+	/// Is a timer still scheduled, ie it has not expired nor been cancelled?
+	///
+	/// Returns `false` if `timer_key` is out of range (eg it is from a larger timer wheel).
+	#[inline(always)]
+	pub fn is_scheduled(&self, timer_key: TimerKey) -> bool
+	{
+		let TimerKey { index, generation } = timer_key;
+		
+		match self.entries.get(index as usize)
+		{
+			None => false,
+			
+			Some(entry) => !entry.is_free() && entry.generation == generation,
+		}
+	}
+	
+	/// The earliest instant at which a timer might expire.
+	///
+	/// Use this to arm a `timerfd`.
+	#[inline(always)]
+	pub fn next_expiry(&self) -> Option<Instant>
+	{
+		self.next_expiration().map(|(_level, _slot, deadline)| self.start + Duration::from_nanos(deadline.saturating_mul(self.tick_in_nanoseconds)))
+	}
+	
+	/// The time from `now` until the earliest instant at which a timer might expire.
+	///
+	/// Use this as the timeout of a reactor's wait; `None` means wait indefinitely.
+	#[inline(always)]
+	pub fn timeout(&self, now: Instant) -> Option<Duration>
+	{
+		self.next_expiry().map(|next_expiry| next_expiry.saturating_duration_since(now))
+	}
+	
+	/// Removes every timer that has expired at `now` and calls `expired` for each.
+	///
+	/// Handles are passed as scheduled; they may be for coroutines which have since completed or been cancelled.
+	#[inline(always)]
+	pub fn expire(&mut self, now: Instant, mut expired: impl FnMut(CoroutineInstanceHandle, TimerPurpose))
+	{
+		let now = self.ticks_rounded_down(now);
+		
+		while let Some((level, slot, deadline)) = self.next_expiration()
+		{
+			if deadline > now
+			{
+				break
+			}
+			self.elapsed = deadline;
+			
+			let mut index = self.take_slot(level, slot);
+			while index != TimerEntry::None
+			{
+				let entry = self.entry(index);
+				let next = entry.next;
+				
+				if entry.when <= self.elapsed
+				{
+					let coroutine_instance_handle = entry.coroutine_instance_handle;
+					let purpose = entry.purpose;
+					self.release(index);
+					expired(coroutine_instance_handle, purpose)
+				}
+				else
+				{
+					self.insert(index)
+				}
+				
+				index = next;
+			}
+		}
+		
+		if now > self.elapsed
+		{
+			self.elapsed = now
+		}
+	}
+	
+	#[inline(always)]
+	fn insert(&mut self, index: u32)
+	{
+		let when = self.entry(index).when;
+		let level = Self::level_for(self.elapsed, when);
+		let slot = Self::slot_for(when, level);
+		
+		let head = self.slot_heads[level][slot];
+		if head != TimerEntry::None
+		{
+			self.entry_mut(head).previous = index;
+		}
+		
+		let entry = self.entry_mut(index);
+		entry.next = head;
+		entry.previous = TimerEntry::None;
+		entry.level = level as u8;
+		entry.slot = slot as u8;
+		
+		self.slot_heads[level][slot] = index;
+		self.occupied[level] |= 1 << slot;
+	}
+	
+	#[inline(always)]
+	fn unlink(&mut self, index: u32)
+	{
+		let entry = self.entry(index);
+		let (level, slot, next, previous) = (entry.level as usize, entry.slot as usize, entry.next, entry.previous);
+		
+		if previous == TimerEntry::None
+		{
+			self.slot_heads[level][slot] = next;
+			if next == TimerEntry::None
+			{
+				self.occupied[level] &= !(1 << slot);
+			}
+		}
+		else
+		{
+			self.entry_mut(previous).next = next;
+		}
+		
+		if next != TimerEntry::None
+		{
+			self.entry_mut(next).previous = previous;
+		}
+	}
+	
+	#[inline(always)]
+	fn take_slot(&mut self, level: usize, slot: usize) -> u32
+	{
+		self.occupied[level] &= !(1 << slot);
+		replace(&mut self.slot_heads[level][slot], TimerEntry::None)
+	}
+	
+	#[inline(always)]
+	fn release(&mut self, index: u32)
+	{
+		let free_head = self.free_head;
+		
+		let entry = self.entry_mut(index);
+		entry.generation = entry.generation.wrapping_add(1);
+		entry.level = TimerEntry::Free;
+		entry.next = free_head;
+		entry.previous = TimerEntry::None;
+		entry.coroutine_instance_handle = CoroutineInstanceHandle::default();
+		
+		self.free_head = index;
+		self.number_scheduled -= 1;
+	}
+	
+	/// Returns `(level, slot, deadline)` of the next occupied slot.
+	#[inline(always)]
+	fn next_expiration(&self) -> Option<(usize, usize, u64)>
+	{
+		for level in 0 .. Self::NumberOfLevels
+		{
+			let occupied = self.occupied[level];
+			if occupied == 0
+			{
+				continue
+			}
+			
+			let slot_range = Self::slot_range(level);
+			let level_range = slot_range << Self::SlotsPerLevelBits;
+			
+			let now_slot = ((self.elapsed / slot_range) & Self::SlotMask) as u32;
+			let slot = ((occupied.rotate_right(now_slot).trailing_zeros() + now_slot) as u64) & Self::SlotMask;
+			
+			let level_start = self.elapsed & !(level_range - 1);
+			let mut deadline = level_start + slot * slot_range;
+			if deadline < self.elapsed
+			{
+				deadline += level_range
+			}
+			
+			return Some((level, slot as usize, deadline))
+		}
+		None
+	}
+	
+	#[inline(always)]
+	fn level_for(elapsed: u64, when: u64) -> usize
+	{
+		let masked = min((elapsed ^ when) | Self::SlotMask, Self::MaximumTicks - 1);
+		let significant_bit = 63 - masked.leading_zeros();
+		(significant_bit / Self::SlotsPerLevelBits) as usize
+	}
+	
+	#[inline(always)]
+	fn slot_for(when: u64, level: usize) -> usize
+	{
+		((when >> (level as u32 * Self::SlotsPerLevelBits)) & Self::SlotMask) as usize
+	}
+	
+	#[inline(always)]
+	const fn slot_range(level: usize) -> u64
+	{
+		1 << (level as u64 * Self::SlotsPerLevelBits as u64)
+	}
+	
+	#[inline(always)]
+	fn ticks_rounded_up(&self, instant: Instant) -> u64
+	{
+		let nanoseconds = self.nanoseconds_since_start(instant);
+		let tick = self.tick_in_nanoseconds as u128;
+		((nanoseconds + tick - 1) / tick) as u64
+	}
+	
+	#[inline(always)]
+	fn ticks_rounded_down(&self, instant: Instant) -> u64
+	{
+		(self.nanoseconds_since_start(instant) / (self.tick_in_nanoseconds as u128)) as u64
+	}
+	
+	#[inline(always)]
+	fn nanoseconds_since_start(&self, instant: Instant) -> u128
+	{
+		instant.saturating_duration_since(self.start).as_nanos()
+	}
+	
+	/// `index` must be in range; it must not come from a caller-supplied `TimerKey` without being checked.
+	#[inline(always)]
+	fn entry(&self, index: u32) -> &TimerEntry
+	{
+		unsafe { self.entries.get_unchecked(index as usize) }
+	}
+	
+	/// `index` must be in range; it must not come from a caller-supplied `TimerKey` without being checked.
+	#[inline(always)]
+	fn entry_mut(&mut self, index: u32) -> &mut TimerEntry
+	{
+		unsafe { self.entries.get_unchecked_mut(index as usize) }
+	}
+}
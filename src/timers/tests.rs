@@ -0,0 +1,129 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn expire_at(timer_wheel: &mut TimerWheel, tick: u64) -> Vec<u64>
+{
+	let now = timer_wheel.start + Duration::from_millis(tick);
+	let mut expired = Vec::new();
+	timer_wheel.expire(now, |coroutine_instance_handle, _purpose| expired.push(coroutine_instance_handle.unwrap()));
+	expired
+}
+
+#[test]
+fn timers_cascade_through_every_level_and_expire_on_their_tick()
+{
+	const Ticks: [u64; 13] =
+	[
+		1,
+		63,
+		64,
+		65,
+		127,
+		4_095,
+		4_096,
+		4_097,
+		(1 << 18) + 1,
+		(1 << 24) + 84,
+		(1 << 30) + 7,
+		(1 << 35) + (1 << 30) + 1,
+		TimerWheel::MaximumTicks - 1,
+	];
+	
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(Ticks.len() as u32).unwrap());
+	let start = timer_wheel.start;
+	for &tick in Ticks.iter().rev()
+	{
+		timer_wheel.schedule(CoroutineInstanceHandle::wrap(tick), start + Duration::from_millis(tick), TimerPurpose::Wake).unwrap();
+	}
+	assert_eq!(timer_wheel.len(), Ticks.len());
+	
+	for &tick in Ticks.iter()
+	{
+		assert_eq!(expire_at(&mut timer_wheel, tick - 1), Vec::<u64>::new(), "expired before tick {}", tick);
+		assert_eq!(expire_at(&mut timer_wheel, tick), vec![tick], "did not expire on tick {}", tick);
+	}
+	assert!(timer_wheel.is_empty());
+	assert_eq!(timer_wheel.next_expiry(), None);
+}
+
+#[test]
+fn timers_beyond_the_last_level_are_clamped()
+{
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(1).unwrap());
+	let start = timer_wheel.start;
+	timer_wheel.schedule(CoroutineInstanceHandle::wrap(1), start + Duration::from_millis(TimerWheel::MaximumTicks * 2), TimerPurpose::LifetimeDeadline).unwrap();
+	
+	assert_eq!(expire_at(&mut timer_wheel, TimerWheel::MaximumTicks - 2), Vec::<u64>::new());
+	assert_eq!(expire_at(&mut timer_wheel, TimerWheel::MaximumTicks - 1), vec![1]);
+}
+
+#[test]
+fn timers_in_one_slot_expire_together()
+{
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(3).unwrap());
+	let start = timer_wheel.start;
+	for handle in 1 ..= 3
+	{
+		timer_wheel.schedule(CoroutineInstanceHandle::wrap(handle), start + Duration::from_millis(200), TimerPurpose::Wake).unwrap();
+	}
+	
+	let mut expired = expire_at(&mut timer_wheel, 250);
+	expired.sort_unstable();
+	assert_eq!(expired, vec![1, 2, 3]);
+}
+
+#[test]
+fn cancelled_timers_do_not_expire_and_free_their_entry()
+{
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(1).unwrap());
+	let start = timer_wheel.start;
+	
+	let timer_key = timer_wheel.schedule(CoroutineInstanceHandle::wrap(1), start + Duration::from_millis(5_000), TimerPurpose::Wake).unwrap();
+	assert!(timer_wheel.schedule(CoroutineInstanceHandle::wrap(2), start + Duration::from_millis(5_000), TimerPurpose::Wake).is_err());
+	assert!(timer_wheel.cancel(timer_key));
+	assert!(!timer_wheel.cancel(timer_key));
+	assert!(timer_wheel.is_empty());
+	
+	timer_wheel.schedule(CoroutineInstanceHandle::wrap(3), start + Duration::from_millis(6_000), TimerPurpose::Wake).unwrap();
+	assert!(!timer_wheel.cancel(timer_key));
+	assert_eq!(expire_at(&mut timer_wheel, 6_000), vec![3]);
+}
+
+#[test]
+fn cancelling_a_timer_key_from_a_larger_timer_wheel_is_ignored()
+{
+	let mut larger_timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(4).unwrap());
+	let start = larger_timer_wheel.start;
+	let mut timer_key = None;
+	for handle in 1 ..= 4
+	{
+		timer_key = Some(larger_timer_wheel.schedule(CoroutineInstanceHandle::wrap(handle), start + Duration::from_millis(5), TimerPurpose::Wake).unwrap());
+	}
+	
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(1).unwrap());
+	timer_wheel.schedule(CoroutineInstanceHandle::wrap(5), timer_wheel.start + Duration::from_millis(5), TimerPurpose::Wake).unwrap();
+	assert!(!timer_wheel.cancel(timer_key.unwrap()));
+	assert_eq!(expire_at(&mut timer_wheel, 5), vec![5]);
+}
+
+#[test]
+fn timers_are_no_longer_scheduled_once_expired_or_cancelled()
+{
+	let mut timer_wheel = TimerWheel::new(Duration::from_millis(1), NonZeroU32::new(2).unwrap());
+	let start = timer_wheel.start;
+	
+	let expiring = timer_wheel.schedule(CoroutineInstanceHandle::wrap(1), start + Duration::from_millis(10), TimerPurpose::Wake).unwrap();
+	let cancelled = timer_wheel.schedule(CoroutineInstanceHandle::wrap(2), start + Duration::from_millis(20), TimerPurpose::Wake).unwrap();
+	assert!(timer_wheel.is_scheduled(expiring));
+	assert!(timer_wheel.is_scheduled(cancelled));
+	
+	assert!(timer_wheel.cancel(cancelled));
+	assert!(!timer_wheel.is_scheduled(cancelled));
+	
+	assert_eq!(expire_at(&mut timer_wheel, 10), vec![1]);
+	assert!(!timer_wheel.is_scheduled(expiring));
+}
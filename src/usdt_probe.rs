@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Fires the SystemTap SDT (USDT) probe `context_coroutine:$name` with the arguments `coroutine_instance_handle` and coroutine manager index, if the feature `usdt` is enabled.
+///
+/// Probes are a `nop` unless a tracer such as bpftrace or perf is attached, in which case arguments are evaluated.
+macro_rules! usdt_probe
+{
+	($name: ident, $coroutine_instance_handle: expr) =>
+	{
+		#[cfg(feature = "usdt")]
+		{
+			probe_lazy!(context_coroutine, $name, { let coroutine_instance_handle: CoroutineInstanceHandle = $coroutine_instance_handle; coroutine_instance_handle.unwrap() }, { let coroutine_instance_handle: CoroutineInstanceHandle = $coroutine_instance_handle; coroutine_instance_handle.coroutine_manager_index().0 as u64 });
+		}
+	}
+}
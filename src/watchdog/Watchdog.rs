@@ -0,0 +1,134 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A diagnostic thread which reports coroutines that have not yielded for too long, eg a handler hogging an event loop's thread.
+///
+/// Threads running coroutines opt-in using `register_current_thread()`.
+/// Every time control is transferred to or from a coroutine on a registered thread, the coroutine's handle and the time are recorded; the watchdog's thread samples these.
+///
+/// Optionally, the watchdog takes a stack sample of a coroutine it reports by sending a signal to its thread; the signal's handler is replaced for the whole process.
+///
+/// Dropping the watchdog stops its thread.
+#[derive(Debug)]
+pub struct Watchdog
+{
+	shared: Arc<WatchdogShared>,
+	thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Drop for Watchdog
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.shared.stopping.store(true, Release);
+		if let Some(thread) = self.thread.take()
+		{
+			thread.thread().unpark();
+			let _ = thread.join();
+		}
+	}
+}
+
+impl Watchdog
+{
+	/// Starts a watchdog thread which every `sample_interval` checks for coroutines that have been running for longer than `threshold` and passes them to `report` (eg to log them).
+	///
+	/// Each time a coroutine runs for too long it is reported once.
+	///
+	/// If `stack_sample_signal_number` is `Some` (eg `SIGUSR2`), reports include a stack sample.
+	/// The signal's handler is replaced for the whole process; fails if the signal already has a handler, so it must differ from the signal used by any `PreemptionTimer` (eg `SIGURG`).
+	#[inline(always)]
+	pub fn new(threshold: Duration, sample_interval: Duration, stack_sample_signal_number: Option<c_int>, report: impl 'static + FnMut(WatchdogReport) + Send) -> io::Result<Self>
+	{
+		if let Some(signal_number) = stack_sample_signal_number
+		{
+			Self::install_signal_handler(signal_number)?
+		}
+		
+		let shared = Arc::new
+		(
+			WatchdogShared
+			{
+				epoch: Instant::now(),
+				stopping: AtomicBool::new(false),
+				slots: Mutex::new(Vec::new()),
+			}
+		);
+		
+		let thread =
+		{
+			let shared = shared.clone();
+			thread::Builder::new().name("watchdog".to_string()).spawn(move || shared.run(threshold, sample_interval, stack_sample_signal_number, report))?
+		};
+		
+		Ok
+		(
+			Self
+			{
+				shared,
+				thread: Some(thread),
+			}
+		)
+	}
+	
+	/// Registers the current thread.
+	///
+	/// Only one watchdog can be registered with a thread at a time; this panics if the current thread is already registered.
+	#[inline(always)]
+	pub fn register_current_thread<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>(&self, global_allocator: &'static GTACSA) -> WatchdogRegistration<CoroutineHeapSize, GTACSA>
+	{
+		assert!(CurrentWatchdogSlot.get().is_null(), "This thread is already registered with a watchdog");
+		
+		let watchdog_slot =
+		{
+			let _restore = RestoreCurrentAllocatorInUse::switch(global_allocator, CurrentAllocatorInUse::Global);
+			
+			let watchdog_slot = Arc::new(WatchdogSlot::new(self.shared.epoch));
+			self.shared.slots.lock().unwrap().push(watchdog_slot.clone());
+			watchdog_slot
+		};
+		CurrentWatchdogSlot.set(Arc::as_ptr(&watchdog_slot));
+		
+		WatchdogRegistration
+		{
+			global_allocator,
+			watchdog_slot: Some(watchdog_slot),
+			marker: PhantomData,
+		}
+	}
+	
+	/// Fails if `signal_number` already has a handler other than this one (eg it is in use by another library or by the other of `Watchdog` and `PreemptionTimer`).
+	#[inline(always)]
+	fn install_signal_handler(signal_number: c_int) -> io::Result<()>
+	{
+		let mut previous_signal_action: sigaction = unsafe { zeroed() };
+		if unlikely!(unsafe { sigaction(signal_number, null(), &mut previous_signal_action) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		
+		let handler = stack_sample_signal_handler as usize;
+		let previous_handler = previous_signal_action.sa_sigaction;
+		if previous_handler == handler
+		{
+			return Ok(())
+		}
+		if unlikely!(previous_handler != SIG_DFL)
+		{
+			return Err(io::Error::new(io::ErrorKind::AlreadyExists, "signal already has a handler"))
+		}
+		
+		let mut signal_action: sigaction = unsafe { zeroed() };
+		signal_action.sa_sigaction = handler;
+		signal_action.sa_flags = SA_SIGINFO | SA_RESTART;
+		unsafe { sigemptyset(&mut signal_action.sa_mask) };
+		
+		if unlikely!(unsafe { sigaction(signal_number, &signal_action, null_mut()) } == -1)
+		{
+			return Err(io::Error::last_os_error())
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Describes the `CoroutineInformation` of a coroutine reported by a `Watchdog`; set using `CoroutineManager::set_watchdog_describer()`.
+///
+/// Called on the coroutine's own thread when the coroutine is started, so `describe` should only use fields of `CoroutineInformation` that are not changed whilst the coroutine runs; the description is truncated to 128 bytes.
+pub struct WatchdogDescriber<CoroutineInformation: Sized>(fn(&CoroutineInformation, &mut Formatter) -> fmt::Result);
+
+impl<CoroutineInformation: Sized> Debug for WatchdogDescriber<CoroutineInformation>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "WatchdogDescriber({:p})", self.0 as *const ())
+	}
+}
+
+impl<CoroutineInformation: Sized> Clone for WatchdogDescriber<CoroutineInformation>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		*self
+	}
+}
+
+impl<CoroutineInformation: Sized> Copy for WatchdogDescriber<CoroutineInformation>
+{
+}
+
+impl<CoroutineInformation: Sized> WatchdogDescriber<CoroutineInformation>
+{
+	/// New instance.
+	#[inline(always)]
+	pub const fn new(describe: fn(&CoroutineInformation, &mut Formatter) -> fmt::Result) -> Self
+	{
+		Self(describe)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn description(self, coroutine_information: &CoroutineInformation) -> WatchdogDescription
+	{
+		struct Describe<'a, CoroutineInformation: Sized>(fn(&CoroutineInformation, &mut Formatter) -> fmt::Result, &'a CoroutineInformation);
+		
+		impl<'a, CoroutineInformation: Sized> Display for Describe<'a, CoroutineInformation>
+		{
+			#[inline(always)]
+			fn fmt(&self, f: &mut Formatter) -> fmt::Result
+			{
+				(self.0)(self.1, f)
+			}
+		}
+		
+		let mut watchdog_description = WatchdogDescription::default();
+		let _ = fmt::Write::write_fmt(&mut watchdog_description, format_args!("{}", Describe(self.0, coroutine_information)));
+		watchdog_description
+	}
+}
@@ -0,0 +1,64 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A description of a coroutine's `CoroutineInformation`, rendered by a `WatchdogDescriber` on the coroutine's own thread.
+///
+/// Only these bytes are published to the watchdog's thread; descriptions longer than `MaximumLength` are truncated.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct WatchdogDescription
+{
+	length: usize,
+	bytes: [u8; WatchdogDescription::MaximumLength],
+}
+
+impl Default for WatchdogDescription
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			length: 0,
+			bytes: [0; WatchdogDescription::MaximumLength],
+		}
+	}
+}
+
+impl Display for WatchdogDescription
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.write_str(&String::from_utf8_lossy(self.as_bytes()))
+	}
+}
+
+impl fmt::Write for WatchdogDescription
+{
+	/// Truncates, at a character boundary, rather than failing.
+	#[inline(always)]
+	fn write_str(&mut self, s: &str) -> fmt::Result
+	{
+		let mut end = min(s.len(), Self::MaximumLength - self.length);
+		while !s.is_char_boundary(end)
+		{
+			end -= 1
+		}
+		
+		self.bytes[self.length .. self.length + end].copy_from_slice(&s.as_bytes()[.. end]);
+		self.length += end;
+		Ok(())
+	}
+}
+
+impl WatchdogDescription
+{
+	const MaximumLength: usize = 128;
+	
+	#[inline(always)]
+	fn as_bytes(&self) -> &[u8]
+	{
+		&self.bytes[.. self.length]
+	}
+}
@@ -0,0 +1,29 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Registration of a thread with a `Watchdog`; the thread is unregistered when dropped.
+///
+/// Not `Send`: must be dropped on the thread that registered.
+#[derive(Debug)]
+pub struct WatchdogRegistration<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>>
+{
+	global_allocator: &'static GTACSA,
+	watchdog_slot: Option<Arc<WatchdogSlot>>,
+	marker: PhantomData<(CoroutineHeapSize, *const ())>,
+}
+
+impl<CoroutineHeapSize: MemorySize, GTACSA: 'static + GlobalThreadAndCoroutineSwitchableAllocator<CoroutineHeapSize>> Drop for WatchdogRegistration<CoroutineHeapSize, GTACSA>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		CurrentWatchdogSlot.set(null());
+		
+		let watchdog_slot = self.watchdog_slot.take().unwrap();
+		watchdog_slot.unregistered.store(true, Release);
+		
+		let _restore = RestoreCurrentAllocatorInUse::switch(self.global_allocator, CurrentAllocatorInUse::Global);
+		drop(watchdog_slot)
+	}
+}
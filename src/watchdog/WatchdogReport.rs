@@ -0,0 +1,43 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// A coroutine that has run for longer than a `Watchdog`'s threshold without yielding.
+#[derive(Debug, Clone)]
+pub struct WatchdogReport
+{
+	/// Name of the thread the coroutine is running on, if any.
+	pub thread_name: Option<String>,
+	
+	/// Handle of the coroutine.
+	pub coroutine_instance_handle: CoroutineInstanceHandle,
+	
+	/// How long the coroutine had been running, since it was last started or resumed, when sampled.
+	pub running_for: Duration,
+	
+	/// Description of the coroutine's `CoroutineInformation`, if a `WatchdogDescriber` was set.
+	pub coroutine_information: Option<String>,
+	
+	/// Instruction pointer and return addresses, innermost first; empty unless the watchdog takes stack samples.
+	pub stack_sample: Vec<usize>,
+}
+
+impl Display for WatchdogReport
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "coroutine {:?} on thread {} has not yielded for {:?}", self.coroutine_instance_handle, self.thread_name.as_deref().unwrap_or("<unnamed>"), self.running_for)?;
+		
+		if let Some(ref coroutine_information) = self.coroutine_information
+		{
+			write!(f, " ({})", coroutine_information)?;
+		}
+		
+		for return_address in self.stack_sample.iter()
+		{
+			write!(f, "\n\t{:#018x}", return_address)?;
+		}
+		Ok(())
+	}
+}
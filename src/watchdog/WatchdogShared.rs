@@ -0,0 +1,108 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Shared between a `Watchdog`, its thread and registrations.
+#[derive(Debug)]
+struct WatchdogShared
+{
+	epoch: Instant,
+	stopping: AtomicBool,
+	slots: Mutex<Vec<Arc<WatchdogSlot>>>,
+}
+
+impl WatchdogShared
+{
+	/// Number of times, and interval, to check whether a stack sample has been taken.
+	const StackSampleAttempts: u32 = 100;
+	
+	const StackSampleAttemptInterval: Duration = Duration::from_micros(100);
+	
+	#[inline(always)]
+	fn run(&self, threshold: Duration, sample_interval: Duration, stack_sample_signal_number: Option<c_int>, mut report: impl FnMut(WatchdogReport))
+	{
+		while !self.stopping.load(Acquire)
+		{
+			thread::park_timeout(sample_interval);
+			
+			let slots = self.slots_to_sample();
+			for watchdog_slot in slots.iter()
+			{
+				if let Some(watchdog_report) = Self::sample(watchdog_slot, threshold, stack_sample_signal_number)
+				{
+					report(watchdog_report)
+				}
+			}
+		}
+	}
+	
+	/// Also forgets slots of threads that have unregistered.
+	#[inline(always)]
+	fn slots_to_sample(&self) -> Vec<Arc<WatchdogSlot>>
+	{
+		let mut slots = self.slots.lock().unwrap();
+		slots.retain(|watchdog_slot| !watchdog_slot.unregistered.load(Acquire));
+		slots.clone()
+	}
+	
+	#[inline(always)]
+	fn sample(watchdog_slot: &WatchdogSlot, threshold: Duration, stack_sample_signal_number: Option<c_int>) -> Option<WatchdogReport>
+	{
+		let (sequence, coroutine_instance_handle, switched_at_nanoseconds, stack_top, watchdog_description) = watchdog_slot.read()?;
+		
+		let running_for = watchdog_slot.epoch.elapsed().checked_sub(Duration::from_nanos(switched_at_nanoseconds))?;
+		if running_for < threshold || watchdog_slot.last_reported_sequence.load(Relaxed) == sequence
+		{
+			return None
+		}
+		
+		let stack_sample = match stack_sample_signal_number
+		{
+			Some(signal_number) if stack_top != 0 => Self::stack_sample(watchdog_slot, sequence, signal_number),
+			
+			_ => Vec::new(),
+		};
+		
+		let coroutine_information = watchdog_description.map(|watchdog_description| watchdog_description.to_string());
+		
+		if watchdog_slot.has_switched_since(sequence)
+		{
+			return None
+		}
+		watchdog_slot.last_reported_sequence.store(sequence, Relaxed);
+		
+		Some
+		(
+			WatchdogReport
+			{
+				thread_name: watchdog_slot.thread_name.clone(),
+				coroutine_instance_handle,
+				running_for,
+				coroutine_information,
+				stack_sample,
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn stack_sample(watchdog_slot: &WatchdogSlot, sequence: u64, signal_number: c_int) -> Vec<usize>
+	{
+		watchdog_slot.stack_sample_requested_sequence.store(sequence, Relaxed);
+		let stack_sample_sequence = watchdog_slot.stack_sample_sequence.load(Acquire);
+		if unsafe { pthread_kill(watchdog_slot.thread, signal_number) } != 0
+		{
+			return Vec::new()
+		}
+		
+		for _ in 0 .. Self::StackSampleAttempts
+		{
+			if watchdog_slot.stack_sample_sequence.load(Acquire) != stack_sample_sequence
+			{
+				let length = watchdog_slot.stack_sample_length.load(Relaxed);
+				return watchdog_slot.stack_sample[.. length].iter().map(|return_address| return_address.load(Relaxed)).collect()
+			}
+			thread::sleep(Self::StackSampleAttemptInterval)
+		}
+		Vec::new()
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// The slot of the thread registered with a watchdog, if any.
+#[thread_local]
+static CurrentWatchdogSlot: Cell<*const WatchdogSlot> = Cell::new(null());
+
+/// What a registered thread is running and since when.
+///
+/// Written only by the registered thread, as a sequence lock: `sequence` is odd whilst being written.
+#[derive(Debug)]
+struct WatchdogSlot
+{
+	thread: pthread_t,
+	thread_name: Option<String>,
+	epoch: Instant,
+	unregistered: AtomicBool,
+	
+	sequence: AtomicU64,
+	coroutine_instance_handle: AtomicU64,
+	switched_at_nanoseconds: AtomicU64,
+	stack_bottom: AtomicUsize,
+	stack_top: AtomicUsize,
+	description_length: AtomicUsize,
+	description: [AtomicU8; WatchdogDescription::MaximumLength],
+	
+	/// Written by the watchdog's thread before sending a signal for a stack sample; the sample is only taken if the registered thread is still at this `sequence`.
+	stack_sample_requested_sequence: AtomicU64,
+	stack_sample_sequence: AtomicU64,
+	stack_sample_length: AtomicUsize,
+	stack_sample: [AtomicUsize; WatchdogSlot::MaximumStackSampleDepth],
+	
+	/// Only used by the watchdog's thread.
+	last_reported_sequence: AtomicU64,
+}
+
+impl WatchdogSlot
+{
+	const MaximumStackSampleDepth: usize = 32;
+	
+	/// Not a valid `CoroutineInstanceHandle`, as the `is_coroutine` bit is clear.
+	const NoCoroutine: u64 = 0;
+	
+	/// A `description_length` that means there is no description.
+	const NoDescription: usize = usize::MAX;
+	
+	#[inline(always)]
+	fn new(epoch: Instant) -> Self
+	{
+		Self
+		{
+			thread: unsafe { pthread_self() },
+			thread_name: thread::current().name().map(String::from),
+			epoch,
+			unregistered: AtomicBool::new(false),
+			
+			sequence: AtomicU64::new(0),
+			coroutine_instance_handle: AtomicU64::new(Self::NoCoroutine),
+			switched_at_nanoseconds: AtomicU64::new(0),
+			stack_bottom: AtomicUsize::new(0),
+			stack_top: AtomicUsize::new(0),
+			description_length: AtomicUsize::new(Self::NoDescription),
+			description: unsafe { zeroed() },
+			
+			stack_sample_requested_sequence: AtomicU64::new(0),
+			stack_sample_sequence: AtomicU64::new(0),
+			stack_sample_length: AtomicUsize::new(0),
+			stack_sample: unsafe { zeroed() },
+			
+			last_reported_sequence: AtomicU64::new(u64::MAX),
+		}
+	}
+	
+	/// Called by the registered thread.
+	#[inline(always)]
+	fn switched_to(&self, coroutine_instance_handle: Option<CoroutineInstanceHandle>, stack_bottom: usize, stack_top: usize, watchdog_description: Option<&WatchdogDescription>)
+	{
+		let switched_at_nanoseconds = self.epoch.elapsed().as_nanos() as u64;
+		
+		let sequence = self.sequence.load(Relaxed);
+		self.sequence.store(sequence.wrapping_add(1), Relaxed);
+		fence(Release);
+		
+		self.coroutine_instance_handle.store(coroutine_instance_handle.map(CoroutineInstanceHandle::unwrap).unwrap_or(Self::NoCoroutine), Relaxed);
+		self.switched_at_nanoseconds.store(switched_at_nanoseconds, Relaxed);
+		self.stack_bottom.store(stack_bottom, Relaxed);
+		self.stack_top.store(stack_top, Relaxed);
+		match watchdog_description
+		{
+			None => self.description_length.store(Self::NoDescription, Relaxed),
+			
+			Some(watchdog_description) =>
+			{
+				let bytes = watchdog_description.as_bytes();
+				for (index, byte) in bytes.iter().enumerate()
+				{
+					self.description[index].store(*byte, Relaxed);
+				}
+				self.description_length.store(bytes.len(), Relaxed);
+			}
+		}
+		
+		self.sequence.store(sequence.wrapping_add(2), Release);
+	}
+	
+	/// Called by the watchdog's thread; returns `None` if not running a coroutine or in the middle of a switch.
+	#[inline(always)]
+	fn read(&self) -> Option<(u64, CoroutineInstanceHandle, u64, usize, Option<WatchdogDescription>)>
+	{
+		let sequence = self.sequence.load(Acquire);
+		if sequence % 2 == 1
+		{
+			return None
+		}
+		
+		let coroutine_instance_handle = self.coroutine_instance_handle.load(Relaxed);
+		let switched_at_nanoseconds = self.switched_at_nanoseconds.load(Relaxed);
+		let stack_top = self.stack_top.load(Relaxed);
+		let description_length = self.description_length.load(Relaxed);
+		let watchdog_description = if description_length == Self::NoDescription
+		{
+			None
+		}
+		else
+		{
+			let mut watchdog_description = WatchdogDescription::default();
+			let length = min(description_length, WatchdogDescription::MaximumLength);
+			for index in 0 .. length
+			{
+				watchdog_description.bytes[index] = self.description[index].load(Relaxed);
+			}
+			watchdog_description.length = length;
+			Some(watchdog_description)
+		};
+		
+		fence(Acquire);
+		if self.sequence.load(Relaxed) != sequence || coroutine_instance_handle == Self::NoCoroutine
+		{
+			return None
+		}
+		
+		Some((sequence, CoroutineInstanceHandle::wrap(coroutine_instance_handle), switched_at_nanoseconds, stack_top, watchdog_description))
+	}
+	
+	/// Has the registered thread switched since `sequence`?
+	#[inline(always)]
+	fn has_switched_since(&self, sequence: u64) -> bool
+	{
+		fence(Acquire);
+		self.sequence.load(Relaxed) != sequence
+	}
+}
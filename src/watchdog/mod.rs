@@ -0,0 +1,47 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+use super::*;
+use libc::REG_RBP;
+use libc::REG_RIP;
+use libc::REG_RSP;
+use libc::SA_RESTART;
+use libc::SA_SIGINFO;
+use libc::SIG_DFL;
+use libc::c_int;
+use libc::c_void;
+use libc::pthread_kill;
+use libc::pthread_self;
+use libc::pthread_t;
+use libc::sigaction;
+use libc::sigemptyset;
+use libc::siginfo_t;
+use libc::ucontext_t;
+use std::io;
+use std::mem::zeroed;
+use std::ptr::null;
+use std::ptr::null_mut;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::fence;
+use std::sync::atomic::Ordering::Acquire;
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::Ordering::Release;
+
+
+include!("stack_sample_signal_handler.rs");
+include!("watchdog_switched_to.rs");
+
+
+include!("Watchdog.rs");
+include!("WatchdogDescriber.rs");
+include!("WatchdogDescription.rs");
+include!("WatchdogRegistration.rs");
+include!("WatchdogReport.rs");
+include!("WatchdogShared.rs");
+include!("WatchdogSlot.rs");
@@ -0,0 +1,55 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Records the interrupted instruction pointer followed by the return addresses found by walking the chain of frame pointers (`rbp`).
+///
+/// Only frames within the running coroutine's stack are walked, so a stack sample is empty or truncated for code compiled without frame pointers rather than faulting.
+///
+/// If the thread has switched since the watchdog's thread requested the sample, or is part way through a switch, the sample is empty.
+extern "C" fn stack_sample_signal_handler(_signal_number: c_int, _signal_information: *mut siginfo_t, user_context: *mut c_void)
+{
+	let watchdog_slot = CurrentWatchdogSlot.get();
+	if watchdog_slot.is_null()
+	{
+		return
+	}
+	let watchdog_slot = unsafe { &*watchdog_slot };
+	
+	let sequence = watchdog_slot.sequence.load(Acquire);
+	if sequence % 2 == 1 || sequence != watchdog_slot.stack_sample_requested_sequence.load(Relaxed)
+	{
+		watchdog_slot.stack_sample_length.store(0, Relaxed);
+		watchdog_slot.stack_sample_sequence.fetch_add(1, Release);
+		return
+	}
+	
+	let general_registers = unsafe { &(*(user_context as *const ucontext_t)).uc_mcontext.gregs };
+	let instruction_pointer = general_registers[REG_RIP as usize] as usize;
+	let stack_pointer = general_registers[REG_RSP as usize] as usize;
+	let mut frame_pointer = general_registers[REG_RBP as usize] as usize;
+	let stack_bottom = watchdog_slot.stack_bottom.load(Relaxed);
+	let stack_top = watchdog_slot.stack_top.load(Relaxed);
+	
+	watchdog_slot.stack_sample[0].store(instruction_pointer, Relaxed);
+	let mut length = 1;
+	const FrameSize: usize = 2 * size_of::<usize>();
+	while length < WatchdogSlot::MaximumStackSampleDepth && frame_pointer >= stack_pointer && frame_pointer >= stack_bottom && frame_pointer + FrameSize <= stack_top && frame_pointer % size_of::<usize>() == 0
+	{
+		let frame = frame_pointer as *const usize;
+		let return_address = unsafe { *frame.add(1) };
+		let next_frame_pointer = unsafe { *frame };
+		
+		watchdog_slot.stack_sample[length].store(return_address, Relaxed);
+		length += 1;
+		
+		if next_frame_pointer <= frame_pointer
+		{
+			break
+		}
+		frame_pointer = next_frame_pointer;
+	}
+	
+	watchdog_slot.stack_sample_length.store(length, Relaxed);
+	watchdog_slot.stack_sample_sequence.fetch_add(1, Release);
+}
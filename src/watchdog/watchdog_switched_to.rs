@@ -0,0 +1,17 @@
+// This file is part of context-coroutine. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT. No part of context-coroutine, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2020 The developers of context-coroutine. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/context-coroutine/master/COPYRIGHT.
+
+
+/// Records a switch to a coroutine (or, if `None`, to code outside of a coroutine) if this thread is registered with a `Watchdog`.
+///
+/// `stack_bottom` and `stack_top` are the lowest and highest addresses of the coroutine's stack, or zero if not known.
+#[inline(always)]
+pub(crate) fn watchdog_switched_to(coroutine_instance_handle: Option<CoroutineInstanceHandle>, stack_bottom: usize, stack_top: usize, watchdog_description: Option<&WatchdogDescription>)
+{
+	let watchdog_slot = CurrentWatchdogSlot.get();
+	if likely!(watchdog_slot.is_null())
+	{
+		return
+	}
+	unsafe { &*watchdog_slot }.switched_to(coroutine_instance_handle, stack_bottom, stack_top, watchdog_description)
+}